@@ -0,0 +1,81 @@
+//! Suivi de la mémoire allouée sur le tas (fonctionnalité `track-alloc`).
+//!
+//! [`TrackingAllocator`] enveloppe l'allocateur système et comptabilise les
+//! octets actuellement alloués ainsi que le pic atteint. Il doit être
+//! installé comme allocateur global par le binaire ou le test qui souhaite
+//! mesurer la mémoire :
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static GLOBAL: fib_rs::TrackingAllocator = fib_rs::TrackingAllocator;
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Sérialise les appels à [`measure_peak`], qui réinitialisent tous `PEAK`.
+static MEASURE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Allocateur global qui délègue à [`System`] en comptabilisant les octets
+/// alloués et leur pic.
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Ajoute `size` octets au compteur courant et met à jour le pic.
+fn record_alloc(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+/// Retourne le nombre d'octets actuellement alloués via [`TrackingAllocator`].
+pub fn current_bytes() -> usize {
+    CURRENT.load(Ordering::Relaxed)
+}
+
+/// Exécute `f` et retourne son résultat accompagné du pic de mémoire
+/// (en octets) atteint pendant son exécution, au-delà de la mémoire déjà
+/// allouée au moment de l'appel.
+///
+/// Le résultat de `f` fait partie de la mesure ; les compteurs étant
+/// globaux, les allocations d'autres threads pendant l'appel sont également
+/// comptabilisées. Les mesures concurrentes sont en revanche sérialisées :
+/// un appel depuis un autre thread attend la fin de la mesure en cours, et
+/// `f` ne doit donc pas appeler `measure_peak` elle-même. Retourne toujours
+/// 0 octet si [`TrackingAllocator`] n'est pas installé comme allocateur
+/// global.
+///
+pub fn measure_peak<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let _guard = MEASURE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let baseline = current_bytes();
+    PEAK.store(baseline, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK.load(Ordering::Relaxed);
+    (result, peak.saturating_sub(baseline))
+}
@@ -0,0 +1,214 @@
+//! Applications concrètes de la suite de Fibonacci.
+//!
+//! Ce module expose sous des noms explicites des quantités combinatoires
+//! ou géométriques qui s'expriment à l'aide des nombres de Fibonacci.
+
+use num_bigint::BigUint;
+
+use crate::fibonacci_fast_doubling_iterative;
+use crate::golden::SQRT_5;
+
+/// Retourne le nombre de pavages d'un rectangle 2×n par des dominos 2×1.
+///
+/// La colonne la plus à gauche est couverte soit par un domino vertical
+/// (il reste un rectangle 2×(n-1)), soit par deux dominos horizontaux
+/// empilés (il reste un rectangle 2×(n-2)). Le nombre de pavages T(n)
+/// vérifie donc T(n) = T(n-1) + T(n-2) avec T(0) = T(1) = 1, d'où
+/// T(n) = F(n+1).
+///
+/// Par exemple, un rectangle 2×3 admet F(4) = 3 pavages.
+///
+pub fn domino_tilings(n: u32) -> BigUint {
+    fibonacci_fast_doubling_iterative(n as u128 + 1)
+}
+
+/// Retourne le nombre de pavages d'une bande 1×n par des carrés 1×1 et des
+/// dominos 1×2.
+///
+/// Le pavage commence soit par un carré (il reste une bande de longueur
+/// n-1), soit par un domino (il reste une bande de longueur n-2) : c'est la
+/// même récurrence que pour [`domino_tilings`], et le nombre de pavages
+/// vaut F(n+1). Il compte aussi les compositions de n en parts 1 et 2.
+///
+pub fn square_tilings(n: u32) -> BigUint {
+    fibonacci_fast_doubling_iterative(n as u128 + 1)
+}
+
+/// Génère `count` points d'un motif de phyllotaxie (modèle de Vogel), comme
+/// la disposition des graines d'un tournesol.
+///
+/// Le point k (k = 0..count) est placé à l'angle k·α, où α = π(3 - √5)
+/// ≈ 137.508° est l'angle d'or, et à la distance `radius_scale`·√k de
+/// l'origine. Les coordonnées renvoyées sont cartésiennes (x, y). Les
+/// spirales visibles dans le motif sont en nombre égal à des nombres de
+/// Fibonacci consécutifs.
+///
+pub fn golden_angle_points(count: u32, radius_scale: f64) -> Vec<(f64, f64)> {
+    let golden_angle = std::f64::consts::PI * (3.0 - SQRT_5);
+    (0..count)
+        .map(|k| {
+            let k = k as f64;
+            let radius = radius_scale * k.sqrt();
+            let (sin, cos) = (k * golden_angle).sin_cos();
+            (radius * cos, radius * sin)
+        })
+        .collect()
+}
+
+/// Additionne deux nombres écrits en base de Fibonacci (représentation de
+/// Zeckendorf) et retourne la forme canonique de la somme.
+///
+/// Un nombre est représenté par ses chiffres, du moins significatif au plus
+/// significatif : `digits[i] == true` signifie que F(i+2) fait partie de la
+/// somme (poids 1, 2, 3, 5, 8, ...). La forme canonique ne contient jamais
+/// deux `true` consécutifs et n'a pas de `false` de tête ; zéro est le
+/// vecteur vide.
+///
+/// Les chiffres sont d'abord additionnés position par position (valeurs 0,
+/// 1 ou 2), puis renormalisés à l'aide de deux règles de réécriture qui
+/// préservent la valeur :
+///
+/// * deux chiffres consécutifs : F(k) + F(k+1) = F(k+2) ;
+/// * un chiffre double : 2·F(k) = F(k+1) + F(k-2), avec les cas de bord
+///   2·F(2) = F(3) et 2·F(3) = F(4) + F(2).
+///
+/// Les entrées n'ont pas besoin d'être canoniques.
+///
+pub fn fibonacci_base_add(a: &[bool], b: &[bool]) -> Vec<bool> {
+    let len = a.len().max(b.len());
+    let mut digits: Vec<u32> = (0..len + 3)
+        .map(|i| {
+            a.get(i).copied().unwrap_or(false) as u32 + b.get(i).copied().unwrap_or(false) as u32
+        })
+        .collect();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        let mut i = 0;
+        while i < digits.len() {
+            if digits[i] == 0 {
+                i += 1;
+                continue;
+            }
+            // Les règles peuvent écrire jusqu'à deux positions plus haut.
+            if digits.len() < i + 3 {
+                digits.resize(i + 3, 0);
+            }
+            if digits[i] >= 2 {
+                digits[i] -= 2;
+                digits[i + 1] += 1;
+                match i {
+                    0 => {}
+                    1 => digits[0] += 1,
+                    _ => digits[i - 2] += 1,
+                }
+                changed = true;
+            }
+            if digits[i] >= 1 && digits[i + 1] >= 1 {
+                digits[i] -= 1;
+                digits[i + 1] -= 1;
+                digits[i + 2] += 1;
+                changed = true;
+            }
+            i += 1;
+        }
+    }
+
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    digits.into_iter().map(|d| d == 1).collect()
+}
+
+/// Retourne l'état des cinq carrés d'une horloge de Fibonacci affichant
+/// l'heure `hour`:`minute`.
+///
+/// L'horloge est formée de carrés de côtés F(1)..F(5) = 1, 1, 2, 3, 5, dont
+/// la somme vaut 12. L'heure (de 1 à 12, l'heure 0 ou 12 allumant tous les
+/// carrés) et le nombre de tranches de cinq minutes (de 0 à 11) sont chacun
+/// décomposés en une somme de côtés. L'élément i du tableau décrit le
+/// carré de côté F(i+1) :
+///
+/// * `0` : éteint (blanc) ;
+/// * `1` : compte pour les heures (rouge) ;
+/// * `2` : compte pour les minutes (vert) ;
+/// * `3` : compte pour les deux (bleu).
+///
+/// Plusieurs décompositions sont en général possibles ; celle retenue est
+/// gloutonne, du plus grand carré au plus petit (le second carré de côté 1
+/// passe avant le premier). Les heures sont lues modulo 12 et les minutes
+/// arrondies à la tranche de cinq minutes inférieure, comme sur l'horloge
+/// d'origine.
+///
+/// # Panics
+///
+/// Panique si `hour > 23` ou `minute > 59`.
+///
+pub fn fibonacci_clock(hour: u8, minute: u8) -> [u8; 5] {
+    assert!(hour < 24, "l'heure doit être comprise entre 0 et 23");
+    assert!(
+        minute < 60,
+        "les minutes doivent être comprises entre 0 et 59"
+    );
+    const SIDES: [u8; 5] = [1, 1, 2, 3, 5];
+
+    let mut state = [0u8; 5];
+    let hours = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+    for (value, flag) in [(hours, 1), (minute / 5, 2)] {
+        let mut remaining = value;
+        for i in (0..SIDES.len()).rev() {
+            if SIDES[i] <= remaining {
+                remaining -= SIDES[i];
+                state[i] |= flag;
+            }
+        }
+    }
+    state
+}
+
+/// Retourne la moyenne de `data` pondérée par les nombres de Fibonacci,
+/// l'échantillon le plus récent ayant le poids le plus fort.
+///
+/// Pour k échantillons, `data[i]` reçoit le poids F(i+1) : le plus ancien
+/// pèse F(1) = 1 et le dernier F(k). Le résultat vaut
+/// `Σ F(i+1)·data[i] / Σ F(i+1)`, la somme des poids étant F(k+2) - 1.
+///
+/// Les poids sont générés en `f64` par additions. Pour que les longues
+/// séries ne dépassent pas la capacité de `f64` (F(1477) y est infini),
+/// les poids et les sommes partielles sont remis à l'échelle ensemble dès
+/// que les poids deviennent trop grands : le rapport final est inchangé,
+/// et les poids des échantillons les plus anciens deviennent simplement
+/// négligeables.
+///
+/// Retourne `f64::NAN` si `data` est vide.
+///
+pub fn fibonacci_weighted_average(data: &[f64]) -> f64 {
+    const RESCALE_ABOVE: f64 = 1e200;
+
+    if data.is_empty() {
+        return f64::NAN;
+    }
+    // (weight, next_weight) = (F(i+1), F(i+2))
+    let (mut weight, mut next_weight) = (1.0f64, 1.0f64);
+    let (mut weighted_sum, mut total_weight) = (0.0f64, 0.0f64);
+    for &sample in data {
+        weighted_sum += weight * sample;
+        total_weight += weight;
+
+        let t = weight + next_weight;
+        weight = next_weight;
+        next_weight = t;
+        if next_weight > RESCALE_ABOVE {
+            let scale = 1.0 / RESCALE_ABOVE;
+            weight *= scale;
+            next_weight *= scale;
+            weighted_sum *= scale;
+            total_weight *= scale;
+        }
+    }
+    weighted_sum / total_weight
+}
@@ -0,0 +1,120 @@
+//! Cache partagé des valeurs F(n) déjà calculées.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use num_bigint::BigUint;
+
+use crate::fibonacci_fast_doubling_iterative;
+
+/// Cache de valeurs F(n), utilisable simultanément depuis plusieurs threads.
+///
+/// Le cache conserve au plus `capacity` valeurs. Lorsqu'il est plein,
+/// l'insertion d'une nouvelle valeur évince celle dont le dernier accès
+/// est le plus ancien (politique LRU). `FibCache` est `Send + Sync` : il
+/// peut être partagé entre plusieurs threads au moyen d'un `Arc`.
+///
+#[derive(Debug)]
+pub struct FibCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+/// État protégé par le verrou de [`FibCache`].
+#[derive(Debug, Default)]
+struct CacheState {
+    /// Valeurs en cache, avec la date de leur dernier accès.
+    entries: HashMap<u128, (BigUint, u64)>,
+    /// Horloge logique, incrémentée à chaque accès.
+    clock: u64,
+}
+
+impl FibCache {
+    /// Crée un cache vide pouvant contenir au plus `capacity` valeurs.
+    ///
+    /// Avec une capacité nulle, aucune valeur n'est conservée et chaque
+    /// appel à [`get`](FibCache::get) recalcule F(n).
+    ///
+    pub fn new(capacity: usize) -> Self {
+        FibCache {
+            capacity,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Retourne F(n), depuis le cache s'il y figure, sinon en le calculant
+    /// avec [`fibonacci_fast_doubling_iterative`] puis en l'y ajoutant.
+    ///
+    /// Le calcul est effectué hors du verrou : les autres threads peuvent
+    /// lire le cache pendant ce temps. Deux threads demandant simultanément
+    /// un même index absent le calculent donc chacun une fois.
+    ///
+    /// # Panics
+    ///
+    /// Panique si un autre thread a paniqué en détenant le verrou du cache.
+    ///
+    pub fn get(&self, n: u128) -> BigUint {
+        {
+            let mut state = self.lock();
+            state.clock += 1;
+            let now = state.clock;
+            if let Some((value, last_used)) = state.entries.get_mut(&n) {
+                *last_used = now;
+                return value.clone();
+            }
+        }
+
+        let value = fibonacci_fast_doubling_iterative(n);
+        if self.capacity == 0 {
+            return value;
+        }
+
+        let mut state = self.lock();
+        state.clock += 1;
+        let now = state.clock;
+        if !state.entries.contains_key(&n) && state.entries.len() >= self.capacity {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(&index, _)| index);
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+        state.entries.insert(n, (value.clone(), now));
+        value
+    }
+
+    /// Retourne le nombre maximal de valeurs conservées.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Retourne le nombre de valeurs actuellement en cache.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Indique si le cache est vide.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Indique si F(n) figure dans le cache, sans modifier l'ordre
+    /// d'éviction.
+    pub fn contains(&self, n: u128) -> bool {
+        self.lock().entries.contains_key(&n)
+    }
+
+    /// Vide le cache.
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.state
+            .lock()
+            .expect("un thread a paniqué en détenant le verrou du cache")
+    }
+}
@@ -0,0 +1,53 @@
+//! Estimation du coût de calcul de F(n) à partir de la structure de `n`.
+//!
+//! Le nombre d'étapes de l'algorithme "Fast Doubling" ne dépend que de la
+//! représentation binaire de l'index : ces fonctions permettent donc de
+//! prédire et de comparer les coûts sans effectuer le moindre calcul.
+
+/// Retourne le nombre exact de multiplications de [`BigUint`] effectuées par
+/// [`fibonacci_fast_doubling_iterative`] pour l'index `n`.
+///
+/// Chaque étape de doubling coûte 3 multiplications (`a * (2b - a)`, `a * a`
+/// et `b * b`, la multiplication par le scalaire 2 n'étant pas comptée),
+/// et l'étape "advance" n'utilise que des additions. Le nombre d'étapes de
+/// doubling est égal au nombre de bits de `n`.
+///
+/// Ainsi `n = 2^k - 1` (k bits) coûte 3k multiplications, alors que
+/// `n = 2^k` (k + 1 bits) en coûte 3(k + 1).
+///
+/// [`BigUint`]: num_bigint::BigUint
+/// [`fibonacci_fast_doubling_iterative`]: crate::fibonacci_fast_doubling_iterative
+///
+pub fn multiplication_count(n: u128) -> u64 {
+    let (doublings, _) = operation_counts(n);
+    3 * doublings as u64
+}
+
+/// Retourne le nombre d'étapes (doubling, advance) effectuées par
+/// l'algorithme "Fast Doubling" pour l'index `n`.
+///
+/// Il y a une étape de doubling par bit de `n` (sa longueur binaire) et une
+/// étape "advance" par bit à 1 (son poids de Hamming). Par exemple,
+/// F(100) = F(0b1100100) nécessite 7 doublings et 3 advances.
+///
+pub fn operation_counts(n: u128) -> (u32, u32) {
+    (128 - n.leading_zeros(), n.count_ones())
+}
+
+/// Retourne un score de coût relatif pour l'index `n`, égal à
+/// `bits + 0.5 · popcount`.
+///
+/// Le score combine les deux quantités de [`operation_counts`] : chaque bit
+/// de `n` compte pour une étape de doubling, et chaque bit à 1 ajoute une
+/// demi-unité pour l'étape "advance" correspondante. Le poids 0.5 est une
+/// heuristique : il ne reflète pas le coût réel d'une addition face aux
+/// multiplications, mais départage des index de même longueur binaire.
+///
+/// Le score n'a de sens que pour comparer des index entre eux, par exemple
+/// pour trier un lot du moins coûteux au plus coûteux. Ainsi, pour k ≥ 4,
+/// `2^k` (k + 1.5) passe avant `2^k - 1` (1.5k).
+///
+pub fn index_cost_score(n: u128) -> f64 {
+    let (doublings, advances) = operation_counts(n);
+    doublings as f64 + 0.5 * advances as f64
+}
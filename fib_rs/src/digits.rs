@@ -0,0 +1,378 @@
+//! Représentations et propriétés des chiffres de F(n).
+//!
+//! Ce module regroupe les fonctions qui décrivent F(n) dans une base donnée
+//! (chiffres décimaux, bits, etc.).
+
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+
+use crate::fibonacci_fast_doubling_iterative;
+use crate::golden::{LOG10_PHI, LOG10_SQRT5};
+
+/// Index en dessous duquel F(n) est calculé exactement : F(n) tient alors
+/// sans perte dans la mantisse de 53 bits d'un `f64`.
+const EXACT_F64_LIMIT: u128 = 79;
+
+/// Marge relative en deçà de laquelle log10(F(n)) est jugé trop proche
+/// d'un entier pour que l'arrondi de la formule soit fiable.
+const DIGIT_COUNT_EPSILON: f64 = 1e-14;
+
+/// Plus grand index pour lequel [`fibonacci_digit_count`] se permet la
+/// vérification exacte (calcul de F(n)).
+const DIGIT_COUNT_EXACT_LIMIT: u128 = 100_000_000;
+
+/// Retourne le nombre de bits à 1 dans la représentation binaire de F(n)
+/// (son poids de Hamming).
+///
+/// Le décompte est fait directement sur les mots de 32 bits du [`BigUint`],
+/// sans conversion en chaîne. F(0) = 0 n'a aucun bit à 1.
+///
+/// [`BigUint`]: num_bigint::BigUint
+///
+pub fn fibonacci_popcount(n: u128) -> u64 {
+    fibonacci_fast_doubling_iterative(n)
+        .to_u32_digits()
+        .iter()
+        .map(|limb| limb.count_ones() as u64)
+        .sum()
+}
+
+/// Retourne les chiffres décimaux de F(n), du plus significatif au moins
+/// significatif, sous forme de valeurs 0 à 9 (et non de caractères ASCII).
+///
+/// F(0) = 0 donne `vec![0]`.
+///
+pub fn fibonacci_decimal_digits(n: u128) -> Vec<u8> {
+    fibonacci_fast_doubling_iterative(n).to_radix_be(10)
+}
+
+/// Convertit `value` en ternaire équilibré, les chiffres -1, 0 et 1 étant
+/// notés `T`, `0` et `1`, du plus significatif au moins significatif.
+///
+/// À chaque étape, le reste r de la division par 3 donne le chiffre : r = 2
+/// est réécrit 3 - 1, c'est-à-dire le chiffre `T` avec une retenue. Zéro
+/// s'écrit `"0"`.
+///
+pub fn to_balanced_ternary(value: &BigUint) -> String {
+    if value.is_zero() {
+        return "0".to_string();
+    }
+
+    let mut digits = Vec::new();
+    let mut v = value.clone();
+    while !v.is_zero() {
+        let remainder = (&v % 3u32).to_u32().expect("le reste est inférieur à 3");
+        v /= 3u32;
+        match remainder {
+            0 => digits.push(b'0'),
+            1 => digits.push(b'1'),
+            _ => {
+                digits.push(b'T');
+                v += 1u32;
+            }
+        }
+    }
+    digits.reverse();
+    String::from_utf8(digits).expect("les chiffres sont ASCII")
+}
+
+/// Retourne log10(F(n)), sans calculer F(n) pour les grands index.
+///
+/// D'après la formule de Binet, F(n) = (φ^n - ψ^n) / √5 avec |ψ| < 1, d'où
+/// log10(F(n)) ≈ n·log10(φ) - log10(√5). L'erreur relative de cette
+/// approximation décroît comme φ^(-2n) ; pour les petits index (n < 79),
+/// la valeur est calculée à partir de F(n) exact.
+///
+/// Pour les index astronomiques, la précision est limitée par celle du
+/// produit n·log10(φ) en `f64` (environ 16 chiffres significatifs).
+/// F(0) = 0 donne `f64::NEG_INFINITY`.
+///
+pub fn fibonacci_log10(n: u128) -> f64 {
+    if n < EXACT_F64_LIMIT {
+        let exact = fibonacci_fast_doubling_iterative(n)
+            .to_f64()
+            .expect("F(n) tient dans un f64 pour n < 79");
+        return exact.log10();
+    }
+    n as f64 * LOG10_PHI - LOG10_SQRT5
+}
+
+/// Retourne le nombre moyen de chiffres décimaux gagnés à chaque terme de
+/// la suite, log10(φ) ≈ 0.20898764.
+///
+/// Comme F(n+1) / F(n) tend vers φ, chaque terme a asymptotiquement
+/// log10(φ) chiffres de plus que le précédent : environ un chiffre tous les
+/// 4.78 termes.
+///
+pub fn digits_per_step() -> f64 {
+    LOG10_PHI
+}
+
+/// Retourne le nombre de chiffres décimaux de F(n) sans convertir F(n) en
+/// chaîne.
+///
+/// Le nombre de chiffres vaut ⌊log10(F(n))⌋ + 1, avec la forme close
+/// log10(F(n)) ≈ n·log10(φ) - log10(5)/2 de [`fibonacci_log10`]. La formule
+/// est exacte sauf lorsque log10(F(n)) tombe extrêmement près d'un entier :
+/// l'erreur d'arrondi de `f64` (de l'ordre de 10^-16 en relatif) peut alors
+/// faire basculer la partie entière. De tels index sont rares (pour
+/// n < 2 000 000, log10(F(n)) ne s'approche jamais d'un entier à moins de
+/// 10^-6) ; par sécurité, lorsque l'écart relatif à l'entier le plus proche
+/// est inférieur à 10^-14, F(n) est calculé et comparé exactement à la
+/// puissance de 10 correspondante.
+///
+/// Cette vérification n'est faite que pour n ≤ 100 000 000 : au-delà,
+/// aucun repli exact n'existe et le résultat repose uniquement sur
+/// l'arithmétique `f64`. Il peut alors, exceptionnellement, être faux d'une
+/// unité si log10(F(n)) tombe à moins de 10^-14 en relatif d'un entier. Le
+/// résultat est saturé à `u64::MAX` pour les index astronomiques (à partir
+/// de n ≈ 8.8·10^19).
+///
+pub fn fibonacci_digit_count(n: u128) -> u64 {
+    digit_count_with_tolerance(n, DIGIT_COUNT_EPSILON)
+}
+
+/// Implémentation de [`fibonacci_digit_count`], dont l'écart relatif
+/// `epsilon` en deçà duquel F(n) est vérifié exactement est un paramètre.
+fn digit_count_with_tolerance(n: u128, epsilon: f64) -> u64 {
+    if n < EXACT_F64_LIMIT {
+        return digit_count_estimate(n);
+    }
+    let log10 = fibonacci_log10(n);
+    let nearest = log10.round();
+    if (log10 - nearest).abs() < epsilon * log10 && n <= DIGIT_COUNT_EXACT_LIMIT {
+        let power = BigUint::from(10u32).pow(nearest as u32);
+        let below = fibonacci_fast_doubling_iterative(n) < power;
+        return nearest as u64 + if below { 0 } else { 1 };
+    }
+    digit_count_estimate(n)
+}
+
+/// Retourne la différence entre le nombre de chiffres décimaux de F(n) et
+/// celui de F(m), négative si `n < m`.
+///
+/// Les nombres de chiffres sont ceux de [`fibonacci_digit_count`] : ils
+/// sont obtenus par la formule logarithmique et vérifiés exactement dans les
+/// mêmes cas que cette fonction. La différence est saturée aux bornes de
+/// `i64`.
+///
+pub fn digits_added_between(m: u128, n: u128) -> i64 {
+    let delta = fibonacci_digit_count(n) as i128 - fibonacci_digit_count(m) as i128;
+    delta.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Retourne le rapport entre le nombre de chiffres décimaux de F(2n) et
+/// celui de F(n).
+///
+/// Comme F(2n) = F(n)·L(n) ≈ √5·F(n)², ce rapport tend vers 2 lorsque n
+/// grandit. Seule la formule du nombre de chiffres est utilisée : aucun
+/// grand entier n'est calculé pour les grands index. Pour les petits index
+/// le rapport est irrégulier (F(0) et F(1) ont tous deux 1 chiffre, d'où un
+/// rapport de 1).
+///
+pub fn digit_growth_ratio(n: u128) -> f64 {
+    let doubled = match n.checked_mul(2) {
+        Some(m) => digit_count_f64(m),
+        // 2n dépasse u128 : on applique directement la formule en f64.
+        None => ((2.0 * n as f64) * LOG10_PHI - LOG10_SQRT5).floor() + 1.0,
+    };
+    doubled / digit_count_f64(n)
+}
+
+/// Nombre de chiffres décimaux de F(n), déduit de [`fibonacci_log10`] et
+/// exprimé en `f64` pour rester représentable quel que soit `n`.
+fn digit_count_f64(n: u128) -> f64 {
+    if n == 0 {
+        return 1.0;
+    }
+    fibonacci_log10(n).floor() + 1.0
+}
+
+/// Nombre de chiffres décimaux de F(n), déduit de [`fibonacci_log10`] et
+/// saturé à `u64::MAX`.
+pub(crate) fn digit_count_estimate(n: u128) -> u64 {
+    if n == 0 {
+        return 1;
+    }
+    (fibonacci_log10(n).floor() as u64).saturating_add(1)
+}
+
+/// Calcule F(n) une seule fois et retourne la valeur accompagnée de son
+/// écriture dans chacune des bases demandées, dans l'ordre de `bases`.
+///
+/// Les chiffres au-delà de 9 sont écrits en lettres minuscules, comme
+/// [`BigUint::to_str_radix`].
+///
+/// # Panics
+///
+/// Panique si une base n'est pas comprise entre 2 et 36 ; toutes les bases
+/// sont vérifiées avant le calcul de F(n).
+///
+pub fn fibonacci_multibase(n: u128, bases: &[u32]) -> (BigUint, Vec<String>) {
+    for &base in bases {
+        assert!(
+            (2..=36).contains(&base),
+            "la base {} n'est pas comprise entre 2 et 36",
+            base
+        );
+    }
+    let value = fibonacci_fast_doubling_iterative(n);
+    let rendered = bases.iter().map(|&base| value.to_str_radix(base)).collect();
+    (value, rendered)
+}
+
+/// Nombre de chiffres décimaux en deçà duquel [`to_decimal_string`] confie
+/// la conversion à [`BigUint::to_string`].
+const DECIMAL_LEAF_DIGITS: usize = 1_000;
+
+/// Taille (en bits) en deçà de laquelle un inverse est calculé par division
+/// exacte plutôt que par itération de Newton.
+const RECIPROCAL_EXACT_BITS: u64 = 4_096;
+
+/// Bits de garde ajoutés à la demi-précision de chaque itération de Newton.
+const RECIPROCAL_GUARD_BITS: u64 = 64;
+
+/// Convertit `x` en chaîne décimale, par découpage récursif.
+///
+/// La conversion de `num-bigint` traite le nombre bloc de chiffres par bloc
+/// de chiffres, en un temps quadratique en sa taille. Ici, `x` est divisé
+/// par une puissance 10^k dont k vaut environ la moitié de ses chiffres ; le
+/// quotient et le reste sont convertis récursivement puis concaténés, le
+/// reste étant complété par des zéros de tête jusqu'à k chiffres. Les
+/// puissances 10^1000, 10^2000, 10^4000, ... sont calculées une seule fois
+/// par élévations au carré successives.
+///
+/// La division de `num-bigint` étant elle aussi quadratique, chaque
+/// division par une puissance est remplacée par une multiplication par son
+/// inverse, calculé une fois par la méthode de Newton, suivie d'une
+/// correction exacte du quotient. Le coût total devient celui de quelques
+/// multiplications (Karatsuba, Toom-3) par niveau de récursion.
+///
+/// Le résultat est identique à `x.to_string()`.
+///
+pub fn to_decimal_string(x: &BigUint) -> String {
+    let leaf = BigUint::from(10u32).pow(DECIMAL_LEAF_DIGITS as u32);
+    if *x < leaf {
+        return x.to_string();
+    }
+    // powers[i] = 10^(DECIMAL_LEAF_DIGITS · 2^i), avec powers[last] <= x.
+    let mut powers = vec![leaf];
+    loop {
+        let last = powers.last().expect("au moins une puissance");
+        let next = last * last;
+        if next > *x {
+            break;
+        }
+        powers.push(next);
+    }
+    let divisors: Vec<Divisor> = powers.into_iter().map(Divisor::new).collect();
+    let mut out = String::new();
+    write_decimal(x, &divisors, divisors.len(), None, &mut out);
+    out
+}
+
+/// Diviseur p accompagné d'une approximation de son inverse
+/// 2^(2·bits(p)) / p, pour diviser par multiplication les valeurs < p².
+struct Divisor {
+    value: BigUint,
+    reciprocal: BigUint,
+}
+
+impl Divisor {
+    fn new(value: BigUint) -> Self {
+        let reciprocal = reciprocal(&value);
+        Divisor { value, reciprocal }
+    }
+
+    /// Retourne (x / p, x % p) pour x < p².
+    fn div_rem(&self, x: &BigUint) -> (BigUint, BigUint) {
+        let shift = 2 * self.value.bits();
+        let mut q = (x * &self.reciprocal) >> shift;
+        // L'inverse est approché : le quotient estimé est corrigé de
+        // quelques unités au plus, dans un sens ou dans l'autre.
+        let mut product = &q * &self.value;
+        while product > *x {
+            q -= 1u32;
+            product -= &self.value;
+        }
+        let mut r = x - product;
+        while r >= self.value {
+            r -= &self.value;
+            q += 1u32;
+        }
+        (q, r)
+    }
+}
+
+/// Retourne une approximation de 2^(2b) / p, où b est le nombre de bits de
+/// `p` (non nul), exacte à quelques unités près.
+///
+/// L'inverse de la moitié haute de `p` est calculé récursivement, remis à
+/// l'échelle, puis affiné par une itération de Newton
+/// r' = 2r - p·r² / 2^(2b), qui double le nombre de bits exacts.
+fn reciprocal(p: &BigUint) -> BigUint {
+    let b = p.bits();
+    if b <= RECIPROCAL_EXACT_BITS {
+        return (BigUint::one() << (2 * b)) / p;
+    }
+    let h = b / 2 + RECIPROCAL_GUARD_BITS;
+    let m = b - h;
+    let r = reciprocal(&(p >> m)) << m;
+    let correction = (p * &r * &r) >> (2 * b);
+    (r << 1u32) - correction
+}
+
+/// Ajoute à `out` l'écriture décimale de `x`, sachant que
+/// x < 10^(DECIMAL_LEAF_DIGITS · 2^level), complétée par des zéros de tête
+/// jusqu'à `width` chiffres si elle est fournie.
+fn write_decimal(
+    x: &BigUint,
+    divisors: &[Divisor],
+    level: usize,
+    width: Option<usize>,
+    out: &mut String,
+) {
+    if level == 0 {
+        let digits = x.to_string();
+        if let Some(width) = width {
+            out.extend(std::iter::repeat_n('0', width.saturating_sub(digits.len())));
+        }
+        out.push_str(&digits);
+        return;
+    }
+    let divisor = &divisors[level - 1];
+    if width.is_none() && *x < divisor.value {
+        // Pas de zéros de tête à écrire : la moitié haute est nulle.
+        write_decimal(x, divisors, level - 1, None, out);
+        return;
+    }
+    let half = DECIMAL_LEAF_DIGITS << (level - 1);
+    let (high, low) = divisor.div_rem(x);
+    write_decimal(&high, divisors, level - 1, width.map(|w| w - half), out);
+    write_decimal(&low, divisors, level - 1, Some(half), out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_count_exact_fallback() {
+        // Avec une tolérance de 1, chaque index passe par la comparaison
+        // exacte à la puissance de 10 la plus proche, par excès ou par
+        // défaut.
+        for n in (EXACT_F64_LIMIT..2_000).chain([276_908]) {
+            let expected = fibonacci_fast_doubling_iterative(n).to_string().len() as u64;
+            assert_eq!(digit_count_with_tolerance(n, 1.0), expected, "n={n}");
+        }
+    }
+
+    #[test]
+    fn test_digit_count_estimate_only() {
+        // Avec une tolérance nulle, seule la formule en f64 est utilisée.
+        for n in (EXACT_F64_LIMIT..2_000).chain([276_908]) {
+            let expected = fibonacci_fast_doubling_iterative(n).to_string().len() as u64;
+            assert_eq!(digit_count_with_tolerance(n, 0.0), expected, "n={n}");
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Type d'erreur de la bibliothèque.
+
+use core::fmt;
+
+/// Erreurs pouvant survenir lors d'un calcul de la bibliothèque.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FibError {
+    /// Le calcul demandé dépasse le nombre d'étapes autorisé.
+    StepLimitExceeded {
+        /// L'index demandé.
+        n: u64,
+        /// Le nombre maximal d'étapes autorisé.
+        max_steps: u64,
+    },
+}
+
+impl fmt::Display for FibError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FibError::StepLimitExceeded { n, max_steps } => write!(
+                f,
+                "le calcul de F({}) nécessite {} étapes, au-delà de la limite de {}",
+                n, n, max_steps
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FibError {}
@@ -5,10 +5,72 @@
 //!
 //! Ce code est structuré comme un 'crate' Rust standard et peut être utilisé
 //! comme dépendance par d'autres projets Rust.
+//!
+//! Sans la fonctionnalité `std` (activée par défaut), la bibliothèque est
+//! `no_std` et ne dépend que de `alloc` : seuls le calcul de F(n) et ses
+//! variantes séquentielles de ce module restent disponibles. Les modules
+//! utilisant les threads, les entrées/sorties ou les fonctions
+//! mathématiques de `f64` nécessitent `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use num_traits::{One, Zero};
 
+#[cfg(feature = "track-alloc")]
+mod alloc_tracking;
+#[cfg(feature = "std")]
+mod applications;
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "std")]
+mod cost;
+#[cfg(feature = "std")]
+mod digits;
+mod error;
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "std")]
+pub mod golden;
+#[cfg(feature = "std")]
+mod identities;
+#[cfg(feature = "std")]
+mod inverse;
+#[cfg(feature = "std")]
+mod modular;
+#[cfg(feature = "std")]
+mod range;
+mod result;
+#[cfg(feature = "std")]
+mod summary;
+
+#[cfg(feature = "track-alloc")]
+pub use alloc_tracking::*;
+#[cfg(feature = "std")]
+pub use applications::*;
+#[cfg(feature = "std")]
+pub use cache::*;
+#[cfg(feature = "std")]
+pub use cost::*;
+#[cfg(feature = "std")]
+pub use digits::*;
+pub use error::FibError;
+#[cfg(feature = "ffi")]
+pub use ffi::*;
+#[cfg(feature = "std")]
+pub use identities::*;
+#[cfg(feature = "std")]
+pub use inverse::*;
+#[cfg(feature = "std")]
+pub use modular::*;
+#[cfg(feature = "std")]
+pub use range::*;
+pub use result::FibResult;
+#[cfg(feature = "std")]
+pub use summary::*;
+
 /// Calcule F(n) en utilisant l'algorithme itératif "Fast Doubling".
 ///
 /// Complexité : O(log n) opérations arithmétiques sur des grands entiers.
@@ -23,7 +85,7 @@ use num_traits::{One, Zero};
 /// # Arguments
 ///
 /// * `n` - L'index (`u128`) du nombre de Fibonacci à calculer. `u128` est
-///         choisi pour permettre une très large plage d'indices.
+///   choisi pour permettre une très large plage d'indices.
 ///
 /// # Returns
 ///
@@ -31,35 +93,449 @@ use num_traits::{One, Zero};
 /// nombres de taille arbitraire.
 ///
 pub fn fibonacci_fast_doubling_iterative(n: u128) -> BigUint {
-    // Cas de base trivial F(0) = 0.
-    if n == 0 {
-        return BigUint::zero();
+    fibonacci_with_progress(n, |_, _| {})
+}
+
+/// Calcule F(n) comme [`fibonacci_fast_doubling_iterative`] en signalant
+/// l'avancement du calcul, par exemple pour afficher une barre de
+/// progression.
+///
+/// `progress(step, total)` est appelé une fois par itération de la boucle,
+/// avec `step` allant de 1 à `total`, où `total` est le nombre de bits de
+/// `n`. F(0) ne produit aucun appel.
+///
+/// Les étapes n'ont pas le même coût : la taille des valeurs double à
+/// chaque itération, si bien que la dernière étape coûte à elle seule à peu
+/// près autant que toutes les précédentes réunies (davantage encore avec
+/// une multiplication sous-quadratique). Une progression linéaire en
+/// `step / total` avance donc vite au début puis ralentit nettement.
+///
+pub fn fibonacci_with_progress(n: u128, mut progress: impl FnMut(u32, u32)) -> BigUint {
+    let total = 128 - n.leading_zeros();
+    fast_doubling_core(n, serial_doubling_step, |bit, _, _, _| {
+        progress(total - bit, total)
+    })
+    .0
+}
+
+/// Calcule F(n) pour un index `n` de taille arbitraire.
+///
+/// La boucle est la même que celle de [`fibonacci_fast_doubling_iterative`],
+/// mais parcourt les bits d'un [`BigUint`] (du bit `n.bits() - 1` au bit 0,
+/// via [`BigUint::bit`]) au lieu de ceux d'un `u128`. Les deux fonctions
+/// donnent le même résultat pour tout index représentable en `u128` ; la
+/// version `u128` reste la plus directe dans ce cas.
+///
+/// F(n) compte environ 0.69·n bits : au-delà de quelques milliards, la
+/// valeur complète ne tient plus en mémoire. Cette fonction est donc surtout
+/// utile comme référence pour le parcours des bits d'un grand index, à
+/// combiner avec une réduction modulaire.
+///
+pub fn fibonacci_fast_doubling_big(n: &BigUint) -> BigUint {
+    let mut a = BigUint::zero();
+    let mut b = BigUint::one();
+    for i in (0..n.bits()).rev() {
+        (a, b) = doubling_step(&a, &b);
+        if n.bit(i) {
+            let t = &a + &b;
+            a = b;
+            b = t;
+        }
     }
+    a
+}
 
-    // Trouve l'index du bit le plus significatif (MSB).
-    let msb_index = 127 - n.leading_zeros();
+/// Calcule F(n) par n additions successives, en refusant les calculs de
+/// plus de `max_steps` étapes.
+///
+/// Complexité : O(n) additions sur des grands entiers. C'est la méthode
+/// "naïve" mais évidemment correcte, utile comme oracle dans les tests ou
+/// pour l'enseignement ; la limite `max_steps` protège contre un lancement
+/// accidentel sur un index gigantesque.
+///
+/// # Errors
+///
+/// Retourne [`FibError::StepLimitExceeded`] si `n > max_steps`.
+///
+pub fn fibonacci_additive_capped(n: u64, max_steps: u64) -> Result<BigUint, FibError> {
+    if n > max_steps {
+        return Err(FibError::StepLimitExceeded { n, max_steps });
+    }
+
+    let mut a = BigUint::zero(); // Représente F(k)
+    let mut b = BigUint::one(); // Représente F(k+1)
+    for _ in 0..n {
+        let t = &a + &b;
+        a = b;
+        b = t;
+    }
+    Ok(a)
+}
+
+/// Calcule F(n) en scindant le calcul au point de contrôle `split`.
+///
+/// Les deux sous-calculs indépendants, (F(split-1), F(split)) et
+/// (F(n-split), F(n-split+1)), sont exécutés sur deux threads distincts
+/// puis recombinés par la formule d'addition :
+///
+/// F(n) = F(split) * F(n-split+1) + F(split-1) * F(n-split)
+///
+/// Choisir `split` proche de `n / 2` équilibre la charge entre les deux
+/// threads.
+///
+/// # Arguments
+///
+/// * `n` - L'index du nombre de Fibonacci à calculer.
+/// * `split` - Le point de scission, avec `split <= n`. `split == 0`
+///   revient au calcul direct.
+///
+/// # Panics
+///
+/// Panique si `split > n`.
+///
+#[cfg(feature = "std")]
+pub fn fibonacci_split(n: u128, split: u128) -> BigUint {
+    assert!(
+        split <= n,
+        "le point de scission ({}) doit être inférieur ou égal à n ({})",
+        split,
+        n
+    );
+    if split == 0 {
+        return fibonacci_fast_doubling_iterative(n);
+    }
+
+    let ((f_split_prev, f_split), (f_rest, f_rest_next)) = std::thread::scope(|scope| {
+        let left = scope.spawn(|| fibonacci_pair(split - 1));
+        let right = fibonacci_pair(n - split);
+        (left.join().expect("le thread de calcul a paniqué"), right)
+    });
+
+    f_split * f_rest_next + f_split_prev * f_rest
+}
+
+/// Calcule le n-ième nombre de Lucas L(n), défini par L(0) = 2, L(1) = 1 et
+/// L(n+2) = L(n+1) + L(n).
+///
+/// Les nombres de Lucas obéissent aux mêmes identités de doubling que ceux
+/// de Fibonacci ; ils se déduisent directement de la paire
+/// (F(n), F(n+1)) renvoyée par [`fibonacci_pair`] :
+///
+/// L(n) = F(n-1) + F(n+1) = 2·F(n+1) - F(n)
+///
+pub fn lucas(n: u128) -> BigUint {
+    let (current, next) = fibonacci_pair(n);
+    next * 2u32 - current
+}
+
+/// Calcule F(n) pour un index signé, en prolongeant la suite aux index
+/// négatifs (nombres "négafibonacci").
+///
+/// La relation F(n-1) = F(n+1) - F(n) appliquée vers la gauche donne
+/// F(-n) = (-1)^(n+1)·F(n) : F(-1) = 1, F(-2) = -1, F(-3) = 2, F(-4) = -3.
+/// La valeur absolue est calculée par [`fibonacci_fast_doubling_iterative`]
+/// puis le signe est appliqué.
+///
+pub fn fibonacci_signed(n: i128) -> BigInt {
+    let magnitude = BigInt::from(fibonacci_fast_doubling_iterative(n.unsigned_abs()));
+    if n < 0 && n % 2 == 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Calcule la paire (G(n), G(n+1)) d'une suite de Fibonacci généralisée
+/// ("gibonacci") définie par G(0) = `a0`, G(1) = `a1` et
+/// G(k+2) = G(k+1) + G(k).
+///
+/// Toute suite de ce type s'exprime à l'aide des nombres de Fibonacci :
+///
+/// G(n)   = a0 * F(n-1) + a1 * F(n)
+/// G(n+1) = a0 * F(n)   + a1 * F(n+1)
+///
+/// Une seule passe de doubling suffit donc. Les graines peuvent être
+/// négatives : (0, 1) donne Fibonacci, (2, 1) les nombres de Lucas.
+///
+pub fn gibonacci_pair(n: u128, a0: &BigInt, a1: &BigInt) -> (BigInt, BigInt) {
+    let (f_n, f_next) = fibonacci_pair(n);
+    let f_n = BigInt::from(f_n);
+    let f_next = BigInt::from(f_next);
+    // F(n-1) = F(n+1) - F(n), y compris pour n = 0 où F(-1) = 1.
+    let f_prev = &f_next - &f_n;
+
+    let g_n = a0 * &f_prev + a1 * &f_n;
+    let g_next = a0 * &f_n + a1 * &f_next;
+    (g_n, g_next)
+}
 
+/// Nature d'une étape de la boucle "Fast Doubling".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceOp {
+    /// Étape de doubling seule (bit à 0) : (F(k), F(k+1)) -> (F(2k), F(2k+1)).
+    Double,
+    /// Doubling suivi d'un "advance" (bit à 1) : -> (F(2k+1), F(2k+2)).
+    DoubleAdvance,
+}
+
+impl TraceOp {
+    /// Retourne le nom de l'opération, tel qu'utilisé dans la trace JSON.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TraceOp::Double => "double",
+            TraceOp::DoubleAdvance => "double_advance",
+        }
+    }
+}
+
+/// Description d'une étape de la boucle "Fast Doubling", sans les valeurs
+/// elles-mêmes (seulement leur taille en bits).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceStep {
+    /// L'index du bit de `n` traité par cette étape (du MSB vers 0).
+    pub bit: u32,
+    /// L'opération effectuée.
+    pub op: TraceOp,
+    /// Le nombre de bits de `a` = F(k) après l'étape.
+    pub a_bits: u64,
+    /// Le nombre de bits de `b` = F(k+1) après l'étape.
+    pub b_bits: u64,
+}
+
+/// Calcule F(n) comme [`fibonacci_fast_doubling_iterative`] en appelant
+/// `on_step` après chaque itération de la boucle.
+///
+/// Chaque [`TraceStep`] ne contient que la taille en bits des deux valeurs
+/// courantes, ce qui permet de tracer des calculs pour lesquels afficher
+/// les valeurs complètes serait impossible. La boucle effectue une étape
+/// par bit de `n`, du plus significatif au moins significatif ; F(0) ne
+/// produit aucune étape.
+///
+pub fn fibonacci_traced(n: u128, mut on_step: impl FnMut(&TraceStep)) -> BigUint {
+    fast_doubling_core(n, serial_doubling_step, |bit, op, a, b| {
+        on_step(&TraceStep {
+            bit,
+            op,
+            a_bits: a.bits(),
+            b_bits: b.bits(),
+        })
+    })
+    .0
+}
+
+/// Calcule la paire (F(n), F(n+1)) avec l'algorithme "Fast Doubling".
+///
+/// La boucle maintient déjà les deux valeurs consécutives à chaque étape ;
+/// cette fonction les renvoie toutes les deux au lieu de ne garder que F(n).
+/// Un appelant qui a besoin de termes adjacents (pour prolonger la suite
+/// par additions, par exemple) évite ainsi un second calcul en O(log n).
+///
+/// # Arguments
+///
+/// * `n` - L'index du premier terme de la paire.
+///
+/// # Returns
+///
+/// Le couple (F(n), F(n+1)) ; pour n = 0, (0, 1).
+///
+pub fn fibonacci_pair(n: u128) -> (BigUint, BigUint) {
+    fast_doubling_core(n, serial_doubling_step, |_, _, _, _| {})
+}
+
+/// Boucle "Fast Doubling" commune, qui appelle `on_step(bit, op, a, b)`
+/// après chaque itération avec l'état (a, b) = (F(k), F(k+1)) courant.
+///
+/// L'étape de doubling elle-même est fournie par `double`, qui consomme
+/// (F(k), F(k+1)) et retourne (F(2k), F(2k+1)) : [`doubling_step`] ou sa
+/// variante parallèle, qui transmet la paire à un thread auxiliaire sans
+/// la copier. Toutes les variantes publiques délèguent à cette
+/// boucle ; avec une closure vide, l'appel à `on_step` est éliminé à la
+/// compilation.
+fn fast_doubling_core(
+    n: u128,
+    mut double: impl FnMut(BigUint, BigUint) -> (BigUint, BigUint),
+    mut on_step: impl FnMut(u32, TraceOp, &BigUint, &BigUint),
+) -> (BigUint, BigUint) {
     // Initialise les états (a, b) = (F(0), F(1))
     let mut a = BigUint::zero(); // Représente F(k)
     let mut b = BigUint::one(); // Représente F(k+1)
 
+    // Cas de base trivial (F(0), F(1)) = (0, 1).
+    if n == 0 {
+        return (a, b);
+    }
+
+    // Trouve l'index du bit le plus significatif (MSB).
+    let msb_index = 127 - n.leading_zeros();
+
     // Itération du MSB (index `msb_index`) jusqu'au LSB (index 0).
     for i in (0..=msb_index).rev() {
         // --- Étape 1: Doubling (toujours exécutée) ---
         // Calcule F(2k) et F(2k+1) à partir de F(k) et F(k+1).
-        let c = &a * (&b * 2u32 - &a);
-        let d = &a * &a + &b * &b;
-        a = c;
-        b = d;
+        (a, b) = double(a, b);
 
         // --- Étape 2: "Advance" (si le bit est '1') ---
         // Si le bit courant de `n` est 1, on avance d'un pas.
         // (F(k), F(k+1)) -> (F(k+1), F(k+2))
-        if (n >> i) & 1 == 1 {
+        let op = if (n >> i) & 1 == 1 {
             let t = &a + &b;
             a = b;
             b = t;
+            TraceOp::DoubleAdvance
+        } else {
+            TraceOp::Double
+        };
+
+        on_step(i, op, &a, &b);
+    }
+    (a, b)
+}
+
+/// Étape de doubling séquentielle :
+/// F(2k)   = F(k) * [2*F(k+1) - F(k)]
+/// F(2k+1) = F(k)^2 + F(k+1)^2
+fn doubling_step(a: &BigUint, b: &BigUint) -> (BigUint, BigUint) {
+    let c = a * (b * 2u32 - a);
+    let d = a * a + b * b;
+    (c, d)
+}
+
+/// [`doubling_step`] sous la forme attendue par [`fast_doubling_core`].
+fn serial_doubling_step(a: BigUint, b: BigUint) -> (BigUint, BigUint) {
+    doubling_step(&a, &b)
+}
+
+/// Taille (en bits) de F(k) à partir de laquelle [`fibonacci_parallel`]
+/// calcule les deux moitiés du doubling sur deux threads. En deçà, le coût
+/// de la synchronisation dépasse celui des multiplications.
+#[cfg(feature = "parallel")]
+pub const PARALLEL_THRESHOLD_BITS: u64 = 1 << 16;
+
+/// Calcule F(n) comme [`fibonacci_fast_doubling_iterative`], en calculant
+/// en parallèle les deux termes indépendants de chaque étape de doubling
+/// (fonctionnalité `parallel`).
+///
+/// À chaque itération, F(2k) = F(k)·[2F(k+1) - F(k)] (une multiplication)
+/// et F(2k+1) = F(k)² + F(k+1)² (deux multiplications) ne dépendent que de
+/// la paire courante. Un unique thread auxiliaire, créé pour toute la durée
+/// de l'appel, reçoit la paire partagée par un `Arc` et calcule le premier
+/// pendant que le thread appelant calcule le second. Ce découpage n'est
+/// appliqué que lorsque F(k) atteint [`PARALLEL_THRESHOLD_BITS`] bits ; les
+/// premières itérations, peu coûteuses, restent séquentielles.
+///
+/// Au mieux, le thread appelant n'attend que ses deux multiplications au
+/// lieu de trois ; le gain réel dépend du nombre de cœurs disponibles et
+/// n'existe pas sur une machine à un seul cœur. Le test ignoré
+/// `bench_parallel_speedup` le mesure :
+/// `cargo test --release --features parallel -- --ignored --nocapture`.
+///
+#[cfg(feature = "parallel")]
+pub fn fibonacci_parallel(n: u128) -> BigUint {
+    use std::sync::{mpsc, Arc};
+
+    std::thread::scope(|scope| {
+        let (pair_tx, pair_rx) = mpsc::channel::<Arc<(BigUint, BigUint)>>();
+        let (c_tx, c_rx) = mpsc::channel::<BigUint>();
+        scope.spawn(move || {
+            for pair in pair_rx {
+                let (a, b) = &*pair;
+                let c = a * (b * 2u32 - a);
+                // Libère la paire avant d'envoyer le résultat, pour que
+                // le thread appelant en redevienne l'unique détenteur.
+                drop(pair);
+                if c_tx.send(c).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let parallel_step = move |a: BigUint, b: BigUint| {
+            if a.bits() < PARALLEL_THRESHOLD_BITS {
+                return doubling_step(&a, &b);
+            }
+            let pair = Arc::new((a, b));
+            pair_tx
+                .send(Arc::clone(&pair))
+                .expect("le thread de calcul a paniqué");
+            let (a, b) = &*pair;
+            let d = a * a + b * b;
+            let c = c_rx.recv().expect("le thread de calcul a paniqué");
+            (c, d)
+        };
+        // `parallel_step` possède `pair_tx` et est consommée par la boucle :
+        // à son retour, le canal est fermé et le thread auxiliaire se termine.
+        fast_doubling_core(n, parallel_step, |_, _, _, _| {}).0
+    })
+}
+
+/// Algorithme de calcul de F(n) sélectionnable par [`fibonacci_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Algorithm {
+    /// "Fast Doubling" itératif ([`fibonacci_fast_doubling_iterative`]).
+    #[default]
+    FastDoubling,
+    /// Exponentiation rapide de la matrice [[1, 1], [1, 0]].
+    MatrixExponentiation,
+}
+
+impl Algorithm {
+    /// Retourne le nom de l'algorithme, tel qu'accepté par l'option `--algo`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Algorithm::FastDoubling => "fast-doubling",
+            Algorithm::MatrixExponentiation => "matrix",
         }
     }
-    a
+}
+
+/// Calcule F(n) avec l'algorithme `algo`.
+///
+/// Les deux algorithmes retournent exactement le même résultat en
+/// O(log n) opérations sur des grands entiers ; ce sélecteur sert à les
+/// comparer. L'exponentiation matricielle effectue environ deux fois plus
+/// de multiplications que le "Fast Doubling" et reste donc plus lente.
+///
+pub fn fibonacci_with(n: u128, algo: Algorithm) -> BigUint {
+    match algo {
+        Algorithm::FastDoubling => fibonacci_fast_doubling_iterative(n),
+        Algorithm::MatrixExponentiation => fibonacci_matrix(n),
+    }
+}
+
+/// Matrice 2×2 de grands entiers, stockée ligne par ligne.
+type Matrix2 = [[BigUint; 2]; 2];
+
+/// Produit de deux matrices 2×2.
+fn matrix_mul(x: &Matrix2, y: &Matrix2) -> Matrix2 {
+    let cell = |i: usize, j: usize| &x[i][0] * &y[0][j] + &x[i][1] * &y[1][j];
+    [[cell(0, 0), cell(0, 1)], [cell(1, 0), cell(1, 1)]]
+}
+
+/// Calcule F(n) par exponentiation rapide de Q = [[1, 1], [1, 0]].
+///
+/// Q^n = [[F(n+1), F(n)], [F(n), F(n-1)]] : F(n) est l'élément en haut à
+/// droite. La puissance est obtenue par élévations au carré successives de
+/// Q, en parcourant les bits de `n` du moins significatif au plus
+/// significatif.
+fn fibonacci_matrix(n: u128) -> BigUint {
+    let mut result: Matrix2 = [
+        [BigUint::one(), BigUint::zero()],
+        [BigUint::zero(), BigUint::one()],
+    ];
+    let mut base: Matrix2 = [
+        [BigUint::one(), BigUint::one()],
+        [BigUint::one(), BigUint::zero()],
+    ];
+    let mut k = n;
+    while k > 0 {
+        if k & 1 == 1 {
+            result = matrix_mul(&result, &base);
+        }
+        k >>= 1;
+        if k > 0 {
+            base = matrix_mul(&base, &base);
+        }
+    }
+    let [[_, top_right], _] = result;
+    top_right
 }
@@ -6,62 +6,691 @@
 //! Ce code est structuré comme un 'crate' Rust standard et peut être compilé
 //! et exécuté avec Cargo.
 
-use fib_rs::fibonacci_fast_doubling_iterative;
+use fib_rs::{
+    fibonacci_fast_doubling_iterative, fibonacci_summary, fibonacci_traced, fibonacci_with,
+    to_balanced_ternary, to_decimal_string, Algorithm, FibResult,
+};
+use num_bigint::BigUint;
 use std::env;
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::ops::RangeInclusive;
+use std::process::ExitCode;
 use std::time::Instant;
 
+#[cfg(feature = "track-alloc")]
+#[global_allocator]
+static GLOBAL: fib_rs::TrackingAllocator = fib_rs::TrackingAllocator;
+
+/// Message d'usage affiché lorsque les arguments sont incomplets.
+const USAGE: &str = "\
+Usage: cargo run --release -- [--algo <fast-doubling|matrix>] [--format <dec|hex|bin|balanced-ternary>] [--summary | --json] [--output <path>] <n|start..end>...
+Où <n> est l'index de Fibonacci à calculer (ex: 1000000) et
+<start..end> une plage inclusive d'index (ex: 50..60).
+       cargo run --release -- --trace-json <n>
+       cargo run --release -- transform <input> <output>
+       cargo run --release --features track-alloc -- memscaling <max> <step>";
+
 /// Point d'entrée principal de l'application en ligne de commande.
 ///
-/// Ce programme analyse l'argument `n` fourni par l'utilisateur,
-/// calcule le n-ième nombre de Fibonacci en utilisant la fonction de la
-/// bibliothèque, mesure le temps d'exécution, et affiche le résultat
-/// de manière concise.
-fn main() {
+/// Toute la logique est dans [`run`], qui écrit sa sortie au fur et à
+/// mesure sur la sortie standard ; `main` se contente d'afficher l'éventuel
+/// message d'erreur sur la sortie d'erreur et de terminer le programme avec
+/// un code de sortie non nul.
+///
+/// `main` retourne un [`ExitCode`] plutôt qu'un `Result<(), Box<dyn Error>>` :
+/// une erreur renvoyée par `main` serait affichée par la bibliothèque
+/// standard avec le préfixe anglais `Error:` et le format `Debug`, alors que
+/// les messages doivent rester `Erreur : …`, comme avant l'introduction de
+/// [`CliError`].
+fn main() -> ExitCode {
     // Récupère les arguments de la ligne de commande
     let args: Vec<String> = env::args().collect();
 
-    // S'attend à un argument exactement : le nombre 'n'
-    if args.len() != 2 {
-        eprintln!("Usage: cargo run --release -- <n>");
-        eprintln!("Où <n> est l'index de Fibonacci à calculer (ex: 1000000).");
-        std::process::exit(1);
+    match run(&args, &mut io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Erreur de l'interface en ligne de commande.
+#[derive(Debug)]
+enum CliError {
+    /// Arguments incomplets ; contient le message d'usage à afficher.
+    Usage(String),
+    /// Valeur d'option absente ; contient le message à afficher.
+    MissingArgument(String),
+    /// Argument présent mais invalide ; contient le message à afficher.
+    Parse(String),
+    /// Certains index ou plages n'ont pas pu être analysés. Les autres ont
+    /// été calculés et leur sortie déjà écrite.
+    InvalidTargets { errors: Vec<String> },
+    /// Sous-commande indisponible dans cette compilation.
+    #[cfg_attr(feature = "track-alloc", allow(dead_code))]
+    Unsupported(String),
+    /// Erreur de lecture ou d'écriture de fichier.
+    Io(io::Error),
+}
+
+/// Le message affiché à l'utilisateur, préfixé de "Erreur : " sauf pour
+/// l'usage ; une ligne par argument rejeté pour `InvalidTargets`.
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}", message),
+            CliError::MissingArgument(message)
+            | CliError::Parse(message)
+            | CliError::Unsupported(message) => write!(f, "Erreur : {}", message),
+            CliError::InvalidTargets { errors } => {
+                let lines: Vec<String> = errors
+                    .iter()
+                    .map(|message| format!("Erreur : {}", message))
+                    .collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+            CliError::Io(e) => write!(f, "Erreur d'entrée/sortie : {}", e),
+        }
+    }
+}
+
+impl Error for CliError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CliError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(e: io::Error) -> Self {
+        CliError::Io(e)
+    }
+}
+
+/// Analyse les arguments de la ligne de commande (`args[0]` étant le nom du
+/// programme), effectue les calculs demandés et écrit leur sortie dans
+/// `out` au fur et à mesure : l'en-tête d'un calcul apparaît avant qu'il ne
+/// commence, et aucun résultat n'est conservé une fois écrit.
+///
+/// # Errors
+///
+/// Retourne une [`CliError`] si un argument manque ou est invalide, ou si un
+/// fichier ne peut être lu ou écrit.
+///
+fn run<W: Write>(args: &[String], out: &mut W) -> Result<(), CliError> {
+    let args = args.get(1..).unwrap_or_default();
+
+    // Sous-commande de mesure de la mémoire : `memscaling <max> <step>`.
+    if args.first().map(String::as_str) == Some("memscaling") {
+        return run_memscaling(&args[1..], out);
+    }
+
+    // Sous-commande de traitement par lot : `transform <input> <output>`.
+    if args.first().map(String::as_str) == Some("transform") {
+        if args.len() != 3 {
+            return Err(CliError::Usage(
+                "Usage: cargo run --release -- transform <input> <output>".to_string(),
+            ));
+        }
+        run_transform(&args[1], &args[2])?;
+        return Ok(());
+    }
+
+    // Sépare les options `--algo <algo>`, `--format <fmt>`, `--summary`,
+    // `--json`, `--output <path>` et `--trace-json <n>` des index.
+    let mut algo = Algorithm::default();
+    let mut format = OutputFormat::Decimal;
+    let mut summary = false;
+    let mut json = false;
+    let mut output_path = None;
+    let mut trace = None;
+    let mut positional = Vec::new();
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        if arg == "--algo" {
+            let value = rest.next().ok_or_else(|| {
+                CliError::MissingArgument("L'option '--algo' attend une valeur.".to_string())
+            })?;
+            algo = parse_algorithm(value)?;
+        } else if arg == "--format" {
+            let value = rest.next().ok_or_else(|| {
+                CliError::MissingArgument("L'option '--format' attend une valeur.".to_string())
+            })?;
+            format = parse_format(value)?;
+        } else if arg == "--summary" {
+            summary = true;
+        } else if arg == "--json" {
+            json = true;
+        } else if arg == "--output" {
+            let path = rest.next().ok_or_else(|| {
+                CliError::MissingArgument(
+                    "L'option '--output' attend un chemin de fichier.".to_string(),
+                )
+            })?;
+            output_path = Some(path);
+        } else if arg == "--trace-json" {
+            let value = rest.next().ok_or_else(|| {
+                CliError::MissingArgument("L'option '--trace-json' attend un index.".to_string())
+            })?;
+            trace = Some(value);
+        } else {
+            positional.push(arg);
+        }
+    }
+
+    // Trace machine du calcul : `--trace-json <n>`, qui occupe à elle seule
+    // toute la ligne de commande.
+    if let Some(value) = trace {
+        if args.len() != 2 {
+            return Err(CliError::Parse(
+                "L'option '--trace-json' est incompatible avec les autres options et index."
+                    .to_string(),
+            ));
+        }
+        let n = parse_index(value)?;
+        out.write_all(trace_json(n).as_bytes())?;
+        return Ok(());
+    }
+
+    // S'attend à au moins un index ou une plage d'index.
+    if positional.is_empty() {
+        return Err(CliError::Usage(USAGE.to_string()));
     }
 
-    // Tente de parser l'argument 'n' en u128
-    let n: u128 = match args[1].parse() {
-        Ok(num) => num,
-        Err(_) => {
-            eprintln!(
-                "Erreur : L'argument '{}' n'est pas un nombre u128 valide.",
-                args[1]
-            );
-            std::process::exit(1);
+    if summary && output_path.is_some() {
+        return Err(CliError::Parse(
+            "Les options '--summary' et '--output' sont incompatibles.".to_string(),
+        ));
+    }
+    if json && (summary || output_path.is_some()) {
+        return Err(CliError::Parse(
+            "L'option '--json' est incompatible avec '--summary' et '--output'.".to_string(),
+        ));
+    }
+    // Un seul index donne un objet JSON ; plusieurs index, ou une plage,
+    // donnent un tableau.
+    let json_array = positional.len() > 1 || positional[0].contains("..");
+
+    // Les résultats complets sont écrits dans le fichier, un par ligne.
+    let mut file = output_path
+        .map(File::create)
+        .transpose()?
+        .map(BufWriter::new);
+
+    // Chaque argument est traité indépendamment : un argument invalide est
+    // signalé sans interrompre le calcul des suivants.
+    let mut first = true;
+    let mut errors = Vec::new();
+    if json && json_array {
+        out.write_all(b"[")?;
+    }
+    for arg in positional {
+        let indices = match parse_target(arg) {
+            Ok(indices) => indices,
+            Err(message) => {
+                errors.push(message);
+                continue;
+            }
+        };
+        for n in indices {
+            if json {
+                if !first {
+                    out.write_all(b",")?;
+                }
+                out.write_all(compute_result(n, algo, format).to_json().as_bytes())?;
+            } else {
+                if !first {
+                    writeln!(out)?;
+                }
+                if summary {
+                    render_summary(n, out)?;
+                } else {
+                    render_result(n, algo, format, file.as_mut(), out)?;
+                }
+            }
+            first = false;
         }
+    }
+    if json && (json_array || !first) {
+        out.write_all(if json_array { b"]\n" } else { b"\n" })?;
+    }
+    if let Some(writer) = file.as_mut() {
+        writer.flush()?;
+    }
+    out.flush()?;
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::InvalidTargets { errors })
+    }
+}
+
+/// Analyse un argument positionnel : un index `n` seul, ou une plage
+/// inclusive `start..end`.
+fn parse_target(arg: &str) -> Result<RangeInclusive<u128>, String> {
+    let parse = |value: &str| {
+        value.parse::<u128>().map_err(|_| {
+            format!(
+                "L'argument '{}' n'est pas un nombre u128 valide ni une plage start..end.",
+                arg
+            )
+        })
     };
+    match arg.split_once("..") {
+        Some((start, end)) => {
+            let (start, end) = (parse(start)?, parse(end)?);
+            if start > end {
+                return Err(format!(
+                    "La plage '{}' est vide (début supérieur à la fin).",
+                    arg
+                ));
+            }
+            Ok(start..=end)
+        }
+        None => {
+            let n = parse(arg)?;
+            Ok(n..=n)
+        }
+    }
+}
 
-    println!(
-        "Calcul de Fibonacci F({}) avec l'algorithme Fast Doubling (Itératif, Optimisé)...",
-        n
-    );
+/// Calcule F(n) et écrit dans `out` la durée du calcul, le nombre de
+/// chiffres et le résultat (tronqué pour les très grands nombres).
+///
+/// L'en-tête est écrit avant le calcul. Si `file` est fourni, le résultat
+/// complet y est écrit sur une ligne au lieu de l'être dans `out`.
+fn render_result<W: Write>(
+    n: u128,
+    algo: Algorithm,
+    format: OutputFormat,
+    file: Option<&mut BufWriter<File>>,
+    out: &mut W,
+) -> io::Result<()> {
+    let algo_name = match algo {
+        Algorithm::FastDoubling => "Fast Doubling (Itératif, Optimisé)",
+        Algorithm::MatrixExponentiation => "d'exponentiation matricielle",
+    };
+    writeln!(
+        out,
+        "Calcul de Fibonacci F({}) avec l'algorithme {}...",
+        n, algo_name
+    )?;
+    out.flush()?;
 
     // Mesure du temps d'exécution
     let start = Instant::now();
-    let result = fibonacci_fast_doubling_iterative(n);
+    let result = fibonacci_with(n, algo);
     let duration = start.elapsed();
 
-    println!("Calcul terminé en {:?}", duration);
+    writeln!(out, "Calcul terminé en {:?}", duration)?;
 
     // --- Affichage du résultat ---
-    let result_str = result.to_string();
+    let result_str = format.render(&result);
     let len = result_str.len();
-    println!("Nombre total de chiffres décimaux: {}", len);
+    writeln!(out, "Nombre total de chiffres {}: {}", format.label(), len)?;
+
+    if let Some(writer) = file {
+        writeln!(writer, "{}", result_str)?;
+        return writeln!(out, "Résultat complet écrit dans le fichier de sortie.");
+    }
 
     // Pour ne pas surcharger le terminal, on n'affiche que les 100
     // premiers et 100 derniers chiffres pour les très grands nombres.
     if len <= 200 {
-        println!("Résultat: {}", result_str);
+        writeln!(out, "Résultat: {}", result_str)
     } else {
-        println!("Début: {}...", &result_str[..100]);
-        println!("Fin:   ...{}", &result_str[len - 100..]);
+        writeln!(out, "Début: {}...", &result_str[..100])?;
+        writeln!(out, "Fin:   ...{}", &result_str[len - 100..])
+    }
+}
+
+/// Calcule F(n) et retourne le résultat structuré émis par `--json`, la
+/// valeur étant écrite au format `format`.
+fn compute_result(n: u128, algo: Algorithm, format: OutputFormat) -> FibResult {
+    let start = Instant::now();
+    let result = fibonacci_with(n, algo);
+    let elapsed = start.elapsed();
+    let value = format.render(&result);
+    FibResult {
+        index: n,
+        digits: value.len() as u64,
+        value,
+        elapsed_micros: elapsed.as_micros(),
+    }
+}
+
+/// Représentation textuelle choisie pour afficher le résultat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// Base 10 (par défaut).
+    Decimal,
+    /// Base 16, chiffres `0`-`9` et `a`-`f`.
+    Hexadecimal,
+    /// Base 2.
+    Binary,
+    /// Ternaire équilibré, chiffres `T`, `0` et `1`.
+    BalancedTernary,
+}
+
+impl OutputFormat {
+    /// Convertit le résultat dans la représentation choisie.
+    fn render(self, value: &BigUint) -> String {
+        match self {
+            OutputFormat::Decimal => to_decimal_string(value),
+            OutputFormat::Hexadecimal => value.to_str_radix(16),
+            OutputFormat::Binary => value.to_str_radix(2),
+            OutputFormat::BalancedTernary => to_balanced_ternary(value),
+        }
+    }
+
+    /// Qualificatif des chiffres affiché dans le décompte.
+    fn label(self) -> &'static str {
+        match self {
+            OutputFormat::Decimal => "décimaux",
+            OutputFormat::Hexadecimal => "hexadécimaux",
+            OutputFormat::Binary => "binaires",
+            OutputFormat::BalancedTernary => "en ternaire équilibré",
+        }
+    }
+}
+
+/// Analyse la valeur de l'option `--algo`.
+fn parse_algorithm(value: &str) -> Result<Algorithm, CliError> {
+    [Algorithm::FastDoubling, Algorithm::MatrixExponentiation]
+        .into_iter()
+        .find(|algo| algo.as_str() == value)
+        .ok_or_else(|| {
+            CliError::Parse(format!(
+                "Algorithme '{}' inconnu (algorithmes acceptés : fast-doubling, matrix).",
+                value
+            ))
+        })
+}
+
+/// Analyse la valeur de l'option `--format`.
+fn parse_format(value: &str) -> Result<OutputFormat, CliError> {
+    match value {
+        "dec" => Ok(OutputFormat::Decimal),
+        "hex" => Ok(OutputFormat::Hexadecimal),
+        "bin" => Ok(OutputFormat::Binary),
+        "balanced-ternary" => Ok(OutputFormat::BalancedTernary),
+        _ => Err(CliError::Parse(format!(
+            "Format '{}' inconnu (formats acceptés : dec, hex, bin, balanced-ternary).",
+            value
+        ))),
+    }
+}
+
+/// Écrit dans `out` le résumé des propriétés de F(n), sans calculer F(n)
+/// pour les grands index.
+fn render_summary<W: Write>(n: u128, out: &mut W) -> io::Result<()> {
+    let summary = fibonacci_summary(n);
+    writeln!(out, "Résumé de F({}) :", n)?;
+    writeln!(out, "  Chiffres décimaux : {}", summary.digit_count)?;
+    writeln!(out, "  Longueur en bits : {}", summary.bit_length)?;
+    match &summary.leading_digits {
+        Some(digits) => writeln!(out, "  Premiers chiffres : {}...", digits)?,
+        None => writeln!(out, "  Premiers chiffres : (hors de la précision de f64)")?,
+    };
+    writeln!(out, "  Derniers chiffres : ...{}", summary.last_digits)?;
+    writeln!(
+        out,
+        "  Parité : {}",
+        if summary.is_even { "pair" } else { "impair" }
+    )?;
+    writeln!(out, "  Multiplications : {}", summary.multiplication_count)
+}
+
+/// Exécute la sous-commande `memscaling <max> <step>`.
+///
+/// Calcule F(n) pour n = step, 2*step, ..., jusqu'à `max` et écrit dans
+/// `out`, ligne par ligne, un CSV `n,peak_bytes,digits` donnant, pour chaque index, le pic de mémoire
+/// du calcul et le nombre de chiffres décimaux du résultat. Chaque résultat
+/// est libéré avant le calcul suivant afin que les mesures ne s'accumulent
+/// pas.
+#[cfg(feature = "track-alloc")]
+fn run_memscaling<W: Write>(args: &[String], out: &mut W) -> Result<(), CliError> {
+    let (max, step) = parse_memscaling_args(args)?;
+
+    writeln!(out, "n,peak_bytes,digits")?;
+    let mut n = step;
+    while n <= max {
+        let (result, peak_bytes) = fib_rs::measure_peak(|| fibonacci_fast_doubling_iterative(n));
+        drop(result);
+        let digits = fib_rs::fibonacci_digit_count(n);
+        writeln!(out, "{},{},{}", n, peak_bytes, digits)?;
+
+        n = match n.checked_add(step) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    Ok(())
+}
+
+/// Sans la fonctionnalité `track-alloc`, aucun allocateur de mesure n'est
+/// installé : la sous-commande est refusée.
+#[cfg(not(feature = "track-alloc"))]
+fn run_memscaling<W: Write>(args: &[String], _out: &mut W) -> Result<(), CliError> {
+    parse_memscaling_args(args)?;
+    Err(CliError::Unsupported(
+        "la sous-commande 'memscaling' nécessite la fonctionnalité 'track-alloc'.\n\
+         Recompilez avec : cargo run --release --features track-alloc -- memscaling <max> <step>"
+            .to_string(),
+    ))
+}
+
+/// Analyse les arguments `<max> <step>` de la sous-commande `memscaling`.
+fn parse_memscaling_args(args: &[String]) -> Result<(u128, u128), CliError> {
+    if args.len() != 2 {
+        return Err(CliError::Usage(
+            "Usage: cargo run --release --features track-alloc -- memscaling <max> <step>"
+                .to_string(),
+        ));
+    }
+    let max = parse_index(&args[0])?;
+    let step = parse_index(&args[1])?;
+    if step == 0 {
+        return Err(CliError::Parse(
+            "Le pas <step> doit être strictement positif.".to_string(),
+        ));
+    }
+    Ok((max, step))
+}
+
+/// Exécute la sous-commande `transform <input> <output>`.
+///
+/// Lit les index du fichier `input` (un par ligne) et écrit F(n) en décimal
+/// dans le fichier `output`, une ligne par index valide. Le traitement se
+/// fait ligne à ligne : seul le résultat courant est conservé en mémoire.
+/// Les lignes vides sont ignorées ; les lignes invalides sont signalées sur
+/// la sortie d'erreur puis sautées. La progression est affichée sur la
+/// sortie d'erreur.
+fn run_transform(input: &str, output: &str) -> io::Result<()> {
+    let reader = BufReader::new(File::open(input)?);
+    let mut writer = BufWriter::new(File::create(output)?);
+
+    let (mut written, mut skipped) = (0u64, 0u64);
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        match trimmed.parse::<u128>() {
+            Ok(n) => {
+                let result = fibonacci_fast_doubling_iterative(n);
+                writeln!(writer, "{}", result)?;
+                written += 1;
+                eprintln!("[transform] ligne {} : F({}) écrit", line_number + 1, n);
+            }
+            Err(_) => {
+                skipped += 1;
+                eprintln!(
+                    "[transform] ligne {} ignorée : '{}' n'est pas un nombre u128 valide.",
+                    line_number + 1,
+                    trimmed
+                );
+            }
+        }
+    }
+    writer.flush()?;
+
+    eprintln!(
+        "[transform] terminé : {} valeur(s) écrite(s), {} ligne(s) ignorée(s).",
+        written, skipped
+    );
+    Ok(())
+}
+
+/// Analyse un index `n` en u128.
+fn parse_index(arg: &str) -> Result<u128, CliError> {
+    arg.parse().map_err(|_| {
+        CliError::Parse(format!(
+            "L'argument '{}' n'est pas un nombre u128 valide.",
+            arg
+        ))
+    })
+}
+
+/// Retourne la trace du calcul de F(n) sous la forme d'un tableau JSON,
+/// un objet `{"bit", "op", "a_bits", "b_bits"}` par étape de la boucle.
+///
+/// Seules les tailles en bits sont émises, jamais les valeurs : la trace
+/// reste compacte même pour des index très grands.
+fn trace_json(n: u128) -> String {
+    let mut out = String::from("[\n");
+    let mut first = true;
+    fibonacci_traced(n, |step| {
+        let separator = if first { "" } else { ",\n" };
+        first = false;
+        let _ = write!(
+            out,
+            "{}  {{\"bit\": {}, \"op\": \"{}\", \"a_bits\": {}, \"b_bits\": {}}}",
+            separator,
+            step.bit,
+            step.op.as_str(),
+            step.a_bits,
+            step.b_bits
+        );
+    });
+    if !first {
+        out.push('\n');
+    }
+    out.push_str("]\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Exécute `run` comme si le binaire était appelé avec `args` et
+    /// retourne son résultat accompagné de la sortie écrite.
+    fn run_capture(args: &[&str]) -> (Result<(), CliError>, String) {
+        let mut full = vec!["fib_rs".to_string()];
+        full.extend(args.iter().map(|arg| arg.to_string()));
+        let mut out = Vec::new();
+        let result = run(&full, &mut out);
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    /// Exécute `run` et retourne la sortie écrite en cas de succès.
+    fn run_with(args: &[&str]) -> Result<String, CliError> {
+        let (result, output) = run_capture(args);
+        result.map(|()| output)
+    }
+
+    #[test]
+    fn test_run_single_index() {
+        let output = run_with(&["10"]).unwrap();
+        assert!(output.starts_with("Calcul de Fibonacci F(10)"));
+        assert!(output.contains("Nombre total de chiffres décimaux: 2\n"));
+        assert!(output.ends_with("Résultat: 55\n"));
+    }
+
+    #[test]
+    fn test_run_without_index_reports_usage() {
+        match run_with(&[]) {
+            Err(CliError::Usage(message)) => assert!(message.starts_with("Usage:")),
+            _ => panic!("une erreur d'usage était attendue"),
+        }
+        assert!(matches!(
+            run_with(&["--format"]),
+            Err(CliError::MissingArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_run_parse_failures() {
+        assert!(matches!(
+            run_with(&["--format", "roman", "10"]),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            run_with(&["--json", "--summary", "10"]),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            run_with(&["--algo", "naive", "10"]),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            run_with(&["--trace-json", "-1"]),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            run_with(&["--trace-json", "10", "5"]),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            run_with(&["--json", "--trace-json", "10"]),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            run_with(&["--trace-json"]),
+            Err(CliError::MissingArgument(_))
+        ));
+        assert!(matches!(
+            run_with(&["memscaling", "10", "0"]),
+            Err(CliError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_run_keeps_valid_results_on_invalid_targets() {
+        match run_capture(&["5", "x", "6"]) {
+            (Err(CliError::InvalidTargets { errors }), output) => {
+                assert!(output.contains("Résultat: 5\n"));
+                assert!(output.contains("Résultat: 8\n"));
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].contains("'x'"));
+            }
+            _ => panic!("une erreur partielle était attendue"),
+        }
+    }
+
+    #[test]
+    fn test_run_io_failure() {
+        let result = run_with(&["transform", "/nonexistent/fib_rs/input.txt", "/tmp/out.txt"]);
+        match result {
+            Err(e @ CliError::Io(_)) => {
+                assert!(e.to_string().starts_with("Erreur d'entrée/sortie"));
+                assert!(e.source().is_some());
+            }
+            _ => panic!("une erreur d'entrée/sortie était attendue"),
+        }
+    }
+
+    #[test]
+    fn test_trace_json_zero() {
+        assert_eq!(trace_json(0), "[\n]\n");
     }
 }
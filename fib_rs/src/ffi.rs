@@ -0,0 +1,87 @@
+//! Interface C pour appeler le calculateur depuis d'autres langages
+//! (fonctionnalité `ffi`).
+//!
+//! Pour produire une bibliothèque partagée utilisable depuis C ou Python
+//! (`ctypes`, `cffi`) :
+//!
+//! ```text
+//! cargo rustc --release --lib --features ffi --crate-type cdylib
+//! ```
+//!
+//! Contrat de propriété : toute chaîne non nulle retournée par
+//! [`fib_compute`] est allouée par Rust et appartient à l'appelant, qui
+//! doit la rendre exactement une fois à [`fib_free`]. Elle ne doit jamais être libérée par
+//! `free()` ni modifiée.
+
+use std::ffi::{c_char, CString};
+use std::panic;
+use std::ptr;
+
+use crate::fibonacci_fast_doubling_iterative;
+
+/// Reconstruit l'index `u128` à partir des deux moitiés passées à
+/// [`fib_compute`] : n = `n_high` · 2^64 + `n_low`.
+fn index_from_halves(n_low: u64, n_high: u64) -> u128 {
+    ((n_high as u128) << 64) | n_low as u128
+}
+
+/// Calcule F(n) et le retourne sous forme de chaîne C décimale terminée par
+/// un octet nul.
+///
+/// L'index est reconstruit à partir de ses deux moitiés :
+/// n = `n_high` · 2^64 + `n_low`.
+///
+/// La chaîne retournée doit être libérée par [`fib_free`]. Une panique
+/// pendant le calcul est interceptée à la frontière `extern "C"` et la
+/// fonction retourne alors un pointeur nul, que l'appelant doit tester.
+/// En revanche, un échec d'allocation termine le processus (`abort`) :
+/// F(n) occupe environ 0.69·n bits, si bien que pour un index trop grand
+/// pour la mémoire disponible (en pratique dès que `n_high` est non nul),
+/// l'appel ne retourne pas.
+///
+#[no_mangle]
+pub extern "C" fn fib_compute(n_low: u64, n_high: u64) -> *mut c_char {
+    let n = index_from_halves(n_low, n_high);
+    let decimal = match panic::catch_unwind(|| fibonacci_fast_doubling_iterative(n).to_string()) {
+        Ok(decimal) => decimal,
+        Err(_) => return ptr::null_mut(),
+    };
+    CString::new(decimal)
+        .expect("une chaîne décimale ne contient pas d'octet nul")
+        .into_raw()
+}
+
+/// Libère une chaîne retournée par [`fib_compute`].
+///
+/// Un pointeur nul est accepté et ignoré.
+///
+/// # Safety
+///
+/// `ptr` doit être nul ou provenir de [`fib_compute`] et ne pas avoir déjà
+/// été libéré. Après l'appel, le pointeur ne doit plus être utilisé.
+///
+#[no_mangle]
+pub unsafe extern "C" fn fib_free(ptr: *mut c_char) {
+    if ptr.is_null() {
+        return;
+    }
+    // SAFETY: d'après le contrat, `ptr` provient de `CString::into_raw` et
+    // n'a pas encore été libéré.
+    drop(unsafe { CString::from_raw(ptr) });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_from_halves() {
+        assert_eq!(index_from_halves(0, 0), 0);
+        assert_eq!(index_from_halves(70_000, 0), 70_000);
+        assert_eq!(index_from_halves(0, 1), 1u128 << 64);
+        assert_eq!(index_from_halves(5, 3), (3u128 << 64) + 5);
+        assert_eq!(index_from_halves(u64::MAX, u64::MAX), u128::MAX);
+        let n = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        assert_eq!(index_from_halves(n as u64, (n >> 64) as u64), n);
+    }
+}
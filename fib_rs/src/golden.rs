@@ -0,0 +1,54 @@
+//! Constantes de la décomposition propre de la suite de Fibonacci.
+//!
+//! La matrice [[1, 1], [1, 0]] a pour valeurs propres φ = (1 + √5) / 2 et
+//! ψ = (1 - √5) / 2, d'où la formule de Binet F(n) = (φ^n - ψ^n) / √5. Ce
+//! module centralise ces constantes, utilisées par les estimations de
+//! magnitude et de nombre de chiffres, pour que les appelants puissent
+//! composer leurs propres formules fermées.
+//!
+//! Toutes les constantes sont les `f64` les plus proches de la valeur
+//! exacte : leur erreur relative est d'au plus une demi-unité du dernier
+//! bit, soit environ 1.1e-16.
+
+/// Le nombre d'or φ = (1 + √5) / 2.
+pub const PHI: f64 = 1.618_033_988_749_895;
+
+/// Le conjugué ψ = (1 - √5) / 2 = 1 - φ = -1/φ.
+pub const PSI: f64 = -0.618_033_988_749_894_9;
+
+/// √5, l'écart φ - ψ entre les deux valeurs propres.
+pub const SQRT_5: f64 = 2.236_067_977_499_79;
+
+/// log10(φ).
+pub(crate) const LOG10_PHI: f64 = 0.20898764024997873;
+
+/// log10(√5).
+pub(crate) const LOG10_SQRT5: f64 = 0.34948500216800943;
+
+/// Retourne le couple (φ^n, ψ^n), les deux termes de la formule de Binet.
+///
+/// Chaque puissance est calculée par `f64::powf`, dont l'erreur relative
+/// reste de l'ordre de quelques unités du dernier bit. Recombinés en
+/// (φ^n - ψ^n) / √5, ils redonnent F(n) exactement après arrondi pour
+/// n ≤ 70 ; au-delà, le résultat n'est qu'une approximation relative
+/// (environ 15 chiffres significatifs). φ^n dépasse la capacité de `f64`
+/// (`f64::INFINITY`) à partir de n = 1475, tandis que ψ^n tend vers 0 en
+/// alternant de signe.
+///
+pub fn binet_terms(n: u32) -> (f64, f64) {
+    let exponent = n as f64;
+    (PHI.powf(exponent), PSI.powf(exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log10_constants_are_nearest_f64() {
+        // Représentations binaires des `f64` les plus proches des valeurs
+        // exactes, calculées en précision étendue.
+        assert_eq!(LOG10_PHI.to_bits(), 0x3fca_c01b_6412_28dc);
+        assert_eq!(LOG10_SQRT5.to_bits(), 0x3fd6_5df6_57b0_4301);
+    }
+}
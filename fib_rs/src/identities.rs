@@ -0,0 +1,195 @@
+//! Identités et séries classiques faisant intervenir la suite de Fibonacci.
+//!
+//! Ces fonctions servent à la fois de démonstrations numériques et de
+//! vérifications de cohérence de l'implémentation.
+
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+
+use crate::fibonacci_pair;
+
+/// Calcule la somme partielle de la série de Millin, Σ 1/F(2^k) pour
+/// k = 0..terms.
+///
+/// La série complète converge vers (7 - √5) / 2 ≈ 2.3819660112501. Les
+/// termes F(2^k) sont exactement ceux produits par les étapes de doubling
+/// successives : (F(k), F(k+1)) -> (F(2k), F(2k+1)).
+///
+/// La convergence est doublement exponentielle : dès que la contribution
+/// d'un terme devient inférieure à la précision de `f64`, les termes
+/// restants (de plus en plus gigantesques) ne sont pas calculés.
+///
+pub fn millin_series(terms: u32) -> f64 {
+    // (a, b) = (F(2^k), F(2^k + 1)), en partant de k = 0.
+    let mut a = BigUint::one();
+    let mut b = BigUint::one();
+    let mut sum = 0.0;
+
+    for _ in 0..terms {
+        let term = 1.0 / a.to_f64().unwrap_or(f64::INFINITY);
+        if term < sum * f64::EPSILON / 2.0 {
+            break;
+        }
+        sum += term;
+
+        let c = &a * (&b * 2u32 - &a);
+        let d = &a * &a + &b * &b;
+        a = c;
+        b = d;
+    }
+    sum
+}
+
+/// Retourne les réduites de la fraction continue de φ = [1; 1, 1, 1, ...].
+///
+/// La k-ième réduite, pour k = 1..=n, vaut F(k+1) / F(k) et est renvoyée
+/// sous la forme du couple (numérateur, dénominateur). Deux nombres de
+/// Fibonacci consécutifs étant premiers entre eux, chaque fraction est
+/// irréductible ; ce sont les meilleures approximations rationnelles de φ,
+/// alternativement par défaut (k impair) et par excès (k pair).
+///
+pub fn phi_convergents(n: u32) -> Vec<(BigUint, BigUint)> {
+    let mut convergents = Vec::with_capacity(n as usize);
+    // (a, b) = (F(k), F(k+1)), en partant de k = 1.
+    let mut a = BigUint::one();
+    let mut b = BigUint::one();
+    for _ in 0..n {
+        let next = &a + &b;
+        convergents.push((b.clone(), a));
+        a = b;
+        b = next;
+    }
+    convergents
+}
+
+/// Vérifie l'identité de Cassini F(n)² - F(n-1)·F(n+1) = (-1)^(n-1).
+///
+/// Les trois termes consécutifs sont obtenus par une seule passe de
+/// doubling (la paire (F(n-1), F(n)), puis F(n+1) par addition). C'est un
+/// contrôle d'intégrité peu coûteux de l'implémentation : la fonction doit
+/// toujours retourner `true`.
+///
+/// Pour n = 0, la convention F(-1) = 1 est utilisée.
+///
+pub fn cassini_check(n: u128) -> bool {
+    let (previous, current) = match n.checked_sub(1) {
+        Some(m) => fibonacci_pair(m),
+        None => (BigUint::one(), BigUint::zero()),
+    };
+    let next = &previous + &current;
+
+    let square = &current * &current;
+    let product = previous * next;
+    if n % 2 == 1 {
+        // (-1)^(n-1) = +1
+        square == product + 1u32
+    } else {
+        // (-1)^(n-1) = -1
+        square + 1u32 == product
+    }
+}
+
+/// Vérifie que (x, y, z) sont trois nombres de Fibonacci consécutifs.
+///
+/// Le triplet doit satisfaire z = x + y ainsi que l'identité de type
+/// Cassini y² - x·z = ±1. Pour des entiers positifs, ces deux conditions
+/// caractérisent exactement les triplets (F(n-1), F(n), F(n+1)) ; un
+/// multiple d'un tel triplet (par exemple (2, 2, 4)) ou un triplet
+/// seulement additif (par exemple (4, 6, 10)) est rejeté.
+///
+pub fn is_fibonacci_product_identity(x: &BigUint, y: &BigUint, z: &BigUint) -> bool {
+    if &(x + y) != z {
+        return false;
+    }
+    let square = y * y;
+    let product = x * z;
+    square == &product + 1u32 || &square + 1u32 == product
+}
+
+/// Calcule la somme des nombres de Lucas L(0) + L(1) + ... + L(n).
+///
+/// L'identité Σ L(k) = L(n+2) - 1 ramène la somme à un seul nombre de
+/// Lucas. Celui-ci s'exprime à partir d'une unique paire de doubling
+/// (F(n), F(n+1)) : L(n+2) = F(n+1) + F(n+3) = F(n) + 3·F(n+1), ce qui évite
+/// tout dépassement de l'index même pour n = `u128::MAX`.
+///
+/// Le terme L(0) = 2 est inclus : la somme vaut 2 pour n = 0.
+///
+pub fn lucas_prefix_sum(n: u128) -> BigUint {
+    let (a, b) = fibonacci_pair(n);
+    a + b * 3u32 - 1u32
+}
+
+/// Vérifie l'identité φ^n = F(n)·φ + F(n-1) dans l'anneau `Z[φ]`.
+///
+/// Tout élément de `Z[φ]` s'écrit a·φ + b avec a, b entiers, et φ² = φ + 1
+/// donne la multiplication
+/// (a·φ + b)(c·φ + d) = (ac + ad + bc)·φ + (ac + bd).
+/// La puissance φ^n est calculée symboliquement dans cette représentation
+/// par exponentiation rapide, sans aucun flottant, puis ses coefficients
+/// entiers sont comparés à la paire (F(n-1), F(n)) issue d'une passe de
+/// doubling. La fonction doit toujours retourner `true`.
+///
+/// Pour n = 0, la convention F(-1) = 1 est utilisée : φ^0 = 0·φ + 1.
+///
+pub fn verify_phi_power_identity(n: u32) -> bool {
+    let multiply = |(a, b): &(BigUint, BigUint), (c, d): &(BigUint, BigUint)| {
+        let ac = a * c;
+        (&ac + a * d + b * c, ac + b * d)
+    };
+
+    // (coefficient de φ, terme constant)
+    let mut power = (BigUint::zero(), BigUint::one());
+    let mut base = (BigUint::one(), BigUint::zero());
+    let mut exponent = n;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            power = multiply(&power, &base);
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = multiply(&base, &base);
+        }
+    }
+
+    let (previous, current) = match n.checked_sub(1) {
+        Some(m) => fibonacci_pair(m as u128),
+        None => (BigUint::one(), BigUint::zero()),
+    };
+    power == (current, previous)
+}
+
+/// Calcule la somme F(0) + F(1) + ... + F(n).
+///
+/// L'identité Σ F(k) = F(n+2) - 1 ramène la somme à un seul terme, en
+/// O(log n) multiplications quel que soit `n`. F(n+2) = F(n) + F(n+1) est
+/// obtenu à partir de la paire (F(n), F(n+1)), ce qui évite de calculer
+/// l'index n + 2 et donc tout dépassement pour n proche de `u128::MAX`.
+///
+pub fn fibonacci_sum(n: u128) -> BigUint {
+    let (a, b) = fibonacci_pair(n);
+    a + b - 1u32
+}
+
+/// Calcule la somme des n premiers termes d'index impair,
+/// F(1) + F(3) + ... + F(2n-1), qui vaut F(2n).
+///
+/// F(2n) = F(n)·(2F(n+1) - F(n)) est obtenu par une étape de doubling
+/// appliquée à la paire (F(n), F(n+1)) : l'index 2n n'est jamais formé et
+/// ne peut donc pas dépasser `u128`. La somme vide (n = 0) vaut 0.
+///
+pub fn fibonacci_sum_odd(n: u128) -> BigUint {
+    let (a, b) = fibonacci_pair(n);
+    &a * (b * 2u32 - &a)
+}
+
+/// Calcule la somme des termes d'index pair F(0) + F(2) + ... + F(2n), qui
+/// vaut F(2n+1) - 1.
+///
+/// F(2n+1) = F(n)² + F(n+1)² est obtenu par une étape de doubling
+/// appliquée à la paire (F(n), F(n+1)), sans former l'index 2n + 1.
+///
+pub fn fibonacci_sum_even(n: u128) -> BigUint {
+    let (a, b) = fibonacci_pair(n);
+    &a * &a + &b * &b - 1u32
+}
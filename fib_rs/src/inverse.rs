@@ -0,0 +1,204 @@
+//! Recherche inverse : retrouver un index n à partir d'une valeur.
+
+use std::collections::HashSet;
+
+use num_bigint::BigUint;
+use num_integer::Roots;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::golden::{LOG10_PHI, LOG10_SQRT5};
+use crate::{fibonacci_fast_doubling_iterative, fibonacci_log10, fibonacci_pair};
+
+/// Retourne l'index n dont F(n) est le plus proche de 10^`log10_value`, en
+/// échelle logarithmique.
+///
+/// C'est l'inverse de [`fibonacci_log10`] : n ≈ (log10_value + log10(√5)) /
+/// log10(φ), arrondi à l'entier le plus proche. Cela permet d'associer un
+/// curseur logarithmique à un index de manière continue.
+///
+/// Les valeurs inférieures à 0.5 (log10_value < log10(0.5)), ainsi que
+/// `NaN`, donnent l'index 0 ; les valeurs autour de 1 donnent l'index 1 ou
+/// 2 (F(1) = F(2) = 1). Les magnitudes dépassant F(u128::MAX) sont
+/// saturées à `u128::MAX`.
+///
+/// [`fibonacci_log10`]: crate::fibonacci_log10
+///
+pub fn index_from_magnitude(log10_value: f64) -> u128 {
+    if log10_value.is_nan() || log10_value < -std::f64::consts::LOG10_2 {
+        return 0;
+    }
+    let estimate = ((log10_value + LOG10_SQRT5) / LOG10_PHI).round();
+    // La conversion `as` sature à u128::MAX pour l'infini.
+    (estimate as u128).max(1)
+}
+
+/// Retourne l'ensemble des nombres de Fibonacci inférieurs ou égaux à
+/// `limit`, construit par additions successives.
+///
+/// L'ensemble contient au plus 93 valeurs (F(93) est le plus grand nombre
+/// de Fibonacci représentable en `u64`) ; `set.contains(&x)` fournit alors
+/// un test d'appartenance en O(1) pour les petites valeurs.
+///
+pub fn fibonacci_set_u64(limit: u64) -> HashSet<u64> {
+    let mut set = HashSet::new();
+    let (mut a, mut b) = (0u64, 1u64);
+    while a <= limit {
+        set.insert(a);
+        match a.checked_add(b) {
+            Some(next) => {
+                a = b;
+                b = next;
+            }
+            None => {
+                // b = F(93) est le dernier terme représentable.
+                if b <= limit {
+                    set.insert(b);
+                }
+                break;
+            }
+        }
+    }
+    set
+}
+
+/// Plus grand index examiné par [`index_hint_from_prefix`].
+const PREFIX_SEARCH_LIMIT: u128 = 1_000_000;
+
+/// Nombre de chiffres du préfixe pris en compte par le test en `f64`.
+const PREFIX_F64_DIGITS: usize = 10;
+
+/// En dessous de cet index, tout candidat est confirmé par un calcul exact,
+/// peu coûteux à cette taille.
+const PREFIX_EXACT_LIMIT: u128 = 10_000;
+
+/// Suggère un index n dont la valeur F(n) commence par les chiffres
+/// décimaux `prefix` ("vouliez-vous dire F(n) ?").
+///
+/// L'index retourné est le plus petit qui convienne. Pour les petits index,
+/// la comparaison est exacte ; au-delà, F(n) commence par le préfixe P à d
+/// chiffres si et seulement si la partie fractionnaire de log10(F(n))
+/// tombe dans [log10(P), log10(P + 1)) - (d - 1), ce qui se teste sans
+/// calculer F(n) grâce à [`fibonacci_log10`]. Seuls les 10 premiers
+/// chiffres du préfixe participent à ce test, avec une marge couvrant
+/// l'erreur d'arrondi ; le candidat est ensuite confirmé par un calcul
+/// exact si le préfixe est plus long ou si n est inférieur à 10 000.
+///
+/// La recherche est approximative (arithmétique `f64`) et bornée aux index
+/// inférieurs à 1 000 000 : la fonction retourne `None` si aucun index ne
+/// convient dans cette plage, ou si `prefix` n'est pas une suite non vide
+/// de chiffres sans zéro de tête (seul `"0"` lui-même désigne F(0)).
+///
+pub fn index_hint_from_prefix(prefix: &str) -> Option<u128> {
+    if prefix.is_empty() || !prefix.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    if prefix == "0" {
+        return Some(0);
+    }
+    if prefix.starts_with('0') {
+        return None;
+    }
+
+    // Petits index : F(n) tient dans un u64 et la comparaison est exacte.
+    let (mut a, mut b) = (1u64, 1u64);
+    let mut n = 1u128;
+    loop {
+        if a.to_string().starts_with(prefix) {
+            return Some(n);
+        }
+        match a.checked_add(b) {
+            Some(next) => {
+                a = b;
+                b = next;
+                n += 1;
+            }
+            None => break,
+        }
+    }
+
+    // Grands index : comparaison de la mantisse logarithmique.
+    let significant = &prefix[..prefix.len().min(PREFIX_F64_DIGITS)];
+    let leading: f64 = significant
+        .parse()
+        .expect("le préfixe ne contient que des chiffres");
+    let shift = (significant.len() - 1) as f64;
+    let lower = leading.log10() - shift;
+    let upper = (leading + 1.0).log10() - shift;
+
+    for n in n..PREFIX_SEARCH_LIMIT {
+        let log10 = fibonacci_log10(n);
+        if log10 + 1.0 < prefix.len() as f64 {
+            // F(n) a moins de chiffres que le préfixe.
+            continue;
+        }
+        let mantissa = log10.fract();
+        let margin = 4.0 * f64::EPSILON * log10;
+        if mantissa < lower - margin || mantissa >= upper + margin {
+            continue;
+        }
+        if (prefix.len() <= PREFIX_F64_DIGITS && n >= PREFIX_EXACT_LIMIT)
+            || fibonacci_fast_doubling_iterative(n)
+                .to_string()
+                .starts_with(prefix)
+        {
+            return Some(n);
+        }
+    }
+    None
+}
+
+/// Indique si `x` est un nombre de Fibonacci.
+///
+/// Utilise la caractérisation classique : x est un nombre de Fibonacci si
+/// et seulement si 5x² + 4 ou 5x² - 4 est un carré parfait. La racine
+/// entière est calculée par [`Roots::sqrt`] puis vérifiée en l'élevant au
+/// carré. Aucun nombre de Fibonacci n'est calculé.
+///
+pub fn is_fibonacci(x: &BigUint) -> bool {
+    let five_x_squared = x * x * 5u32;
+    let is_square = |v: &BigUint| {
+        let root = Roots::sqrt(v);
+        &root * &root == *v
+    };
+    is_square(&(&five_x_squared + 4u32))
+        || (five_x_squared >= BigUint::from(4u32) && is_square(&(five_x_squared - 4u32)))
+}
+
+/// Retourne l'index n tel que F(n) = `x`, ou `None` si `x` n'est pas un
+/// nombre de Fibonacci.
+///
+/// L'index est d'abord estimé à partir de log10(x) par
+/// [`index_from_magnitude`] : les 64 bits de tête de `x` suffisent à
+/// obtenir log10(x) avec la précision de `f64`. L'estimation est ensuite
+/// confirmée exactement sur les trois candidats voisins, obtenus par une
+/// seule passe de doubling.
+///
+/// La valeur 1 étant à la fois F(1) et F(2), c'est l'index 1 qui est
+/// retourné. `fibonacci_index(&0)` vaut `Some(0)`.
+///
+pub fn fibonacci_index(x: &BigUint) -> Option<u128> {
+    if x.is_zero() {
+        return Some(0);
+    }
+    let bits = x.bits();
+    let shift = bits.saturating_sub(64);
+    let top = (x >> shift).to_f64().expect("64 bits tiennent dans un f64");
+    let log10 = top.log10() + shift as f64 * std::f64::consts::LOG10_2;
+    let estimate = index_from_magnitude(log10);
+
+    // Candidats estimate - 1, estimate, estimate + 1.
+    let first = estimate.saturating_sub(1).max(1);
+    let (mut a, mut b) = fibonacci_pair(first);
+    for n in first..=estimate.saturating_add(1) {
+        if a == *x {
+            return Some(n);
+        }
+        if a > *x {
+            break;
+        }
+        let t = &a + &b;
+        a = b;
+        b = t;
+    }
+    None
+}
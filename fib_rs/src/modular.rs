@@ -0,0 +1,435 @@
+//! Calculs de la suite de Fibonacci en arithmétique modulaire.
+//!
+//! Ce module regroupe les fonctions qui travaillent sur F(n) mod m sans
+//! jamais matérialiser le grand entier F(n). Les résidus tiennent dans un
+//! `u64` et les produits intermédiaires sont calculés en `u128`, sauf pour
+//! [`fibonacci_mod`] qui accepte un module de taille arbitraire.
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use crate::fibonacci_fast_doubling_iterative;
+
+/// Calcule F(n) mod `m` pour un module de taille arbitraire.
+///
+/// La boucle est celle de [`fibonacci_fast_doubling_iterative`], mais `a` et
+/// `b` sont réduits modulo `m` après chaque étape de doubling et d'advance :
+/// les valeurs intermédiaires ne dépassent jamais la taille de m², quel que
+/// soit `n`. Comme `a` et `b` sont déjà réduits, le terme 2·F(k+1) - F(k)
+/// est calculé comme 2b + m - a pour ne jamais passer sous zéro.
+///
+/// # Arguments
+///
+/// * `n` - L'index du nombre de Fibonacci.
+/// * `m` - Le module. Pour `m == 1`, le résultat est toujours 0.
+///
+/// # Returns
+///
+/// F(n) mod `m`, ou F(n) non réduit si `m` vaut 0 (aucune réduction n'est
+/// alors possible ; le calcul matérialise le grand entier complet).
+///
+pub fn fibonacci_mod(n: u128, m: &BigUint) -> BigUint {
+    if m.is_zero() {
+        return fibonacci_fast_doubling_iterative(n);
+    }
+    let mut a = BigUint::zero();
+    let mut b = BigUint::one() % m;
+    for i in (0..128 - n.leading_zeros()).rev() {
+        let twice_b_minus_a = (&b * 2u32 + m - &a) % m;
+        let c = (&a * twice_b_minus_a) % m;
+        let d = (&a * &a + &b * &b) % m;
+        if (n >> i) & 1 == 1 {
+            b = (&c + &d) % m;
+            a = d;
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    a
+}
+
+/// Calcule F(n) mod p via la formule de Binet dans le corps fini GF(p).
+///
+/// Lorsque 5 est un résidu quadratique modulo le nombre premier `p`, les
+/// racines φ = (1 + √5) / 2 et ψ = (1 - √5) / 2 du polynôme x² - x - 1
+/// existent dans GF(p), et :
+///
+/// F(n) ≡ (φ^n - ψ^n) / √5 (mod p)
+///
+/// C'est une alternative "algébrique" aux méthodes matricielles ou de
+/// doubling : la racine carrée de 5 est obtenue par l'algorithme de
+/// Tonelli-Shanks, puis φ^n et ψ^n par exponentiation rapide.
+///
+/// # Arguments
+///
+/// * `n` - L'index du nombre de Fibonacci.
+/// * `p` - Un nombre premier impair différent de 5.
+///
+/// # Returns
+///
+/// `Some(F(n) mod p)` si √5 existe et est inversible modulo `p`, `None`
+/// sinon (p non premier, p = 2, p = 5, ou 5 non résidu quadratique).
+///
+pub fn fibonacci_mod_via_phi(n: u128, p: u64) -> Option<u64> {
+    if p < 3 || p == 5 || !is_prime(p) {
+        return None;
+    }
+    // Critère d'Euler : 5 est un résidu quadratique ssi 5^((p-1)/2) ≡ 1.
+    if pow_mod(5, ((p - 1) / 2) as u128, p) != 1 {
+        return None;
+    }
+
+    let sqrt5 = sqrt_mod_prime(5 % p, p);
+    let inv2 = p.div_ceil(2);
+    let phi = mul_mod(add_mod(1, sqrt5, p), inv2, p);
+    let psi = mul_mod(add_mod(1, p - sqrt5, p), inv2, p);
+
+    let diff = add_mod(pow_mod(phi, n, p), p - pow_mod(psi, n, p), p);
+    // √5 est non nul puisque p ≠ 5 : son inverse vaut √5^(p-2) (Fermat).
+    let inv_sqrt5 = pow_mod(sqrt5, (p - 2) as u128, p);
+    Some(mul_mod(diff, inv_sqrt5, p))
+}
+
+/// Itérateur infini sur les résidus F(0) mod m, F(1) mod m, F(2) mod m, ...
+///
+/// Créé par [`fib_mod_iter`]. Chaque étape ne coûte qu'une addition
+/// modulaire sur des `u64`, sans allocation.
+#[derive(Debug, Clone)]
+pub struct FibModIter {
+    a: u64,
+    b: u64,
+    m: u64,
+}
+
+impl Iterator for FibModIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.a;
+        let next = add_mod(self.a, self.b, self.m);
+        self.a = self.b;
+        self.b = next;
+        Some(current)
+    }
+}
+
+/// Retourne un itérateur infini sur la suite de Fibonacci réduite modulo `m`.
+///
+/// # Panics
+///
+/// Panique si `m` vaut 0.
+///
+pub fn fib_mod_iter(m: u64) -> FibModIter {
+    assert!(m != 0, "le module doit être non nul");
+    FibModIter { a: 0, b: 1 % m, m }
+}
+
+/// Retourne les indices n <= `up_to` tels que pgcd(F(n), m) = 1,
+/// c'est-à-dire ceux pour lesquels F(n) est inversible modulo `m`.
+///
+/// Comme pgcd(F(n), m) = pgcd(F(n) mod m, m), seul le résidu est calculé,
+/// par parcours de [`fib_mod_iter`]. Le coût est linéaire en `up_to`.
+///
+/// Pour `m = 1`, tous les indices sont retenus.
+///
+/// # Panics
+///
+/// Panique si `m` vaut 0.
+///
+pub fn fibonacci_coprime_indices(m: u64, up_to: u128) -> Vec<u128> {
+    fib_mod_iter(m)
+        .zip(0..=up_to)
+        .filter(|&(residue, _)| gcd(residue, m) == 1)
+        .map(|(_, n)| n)
+        .collect()
+}
+
+/// Retourne les indices n <= `up_to` tels que le dernier chiffre décimal de
+/// F(n) soit `digit`.
+///
+/// Les derniers chiffres sont périodiques de période π(10) = 60 : seul un
+/// cycle est parcouru avec [`fib_mod_iter`], puis les positions trouvées
+/// sont répétées de 60 en 60. Un chiffre supérieur à 9 donne un vecteur
+/// vide.
+///
+pub fn indices_ending_in(digit: u8, up_to: u128) -> Vec<u128> {
+    const LAST_DIGIT_PERIOD: u128 = 60;
+
+    let positions: Vec<u128> = fib_mod_iter(10)
+        .take(LAST_DIGIT_PERIOD as usize)
+        .enumerate()
+        .filter(|&(_, residue)| residue == digit as u64)
+        .map(|(n, _)| n as u128)
+        .collect();
+
+    let mut indices = Vec::new();
+    let mut base = 0u128;
+    'cycles: loop {
+        for &offset in &positions {
+            match base.checked_add(offset) {
+                Some(n) if n <= up_to => indices.push(n),
+                _ => break 'cycles,
+            }
+        }
+        base = match base.checked_add(LAST_DIGIT_PERIOD) {
+            Some(next) if next <= up_to && !positions.is_empty() => next,
+            _ => break,
+        };
+    }
+    indices
+}
+
+/// Retourne, pour chaque résidu r = 0..base-1, le nombre de fois où
+/// F(n) mod `base` vaut r sur une période de Pisano complète.
+///
+/// La suite F(n) mod `base` est parcourue par [`fib_mod_iter`] sur
+/// exactement π(base) termes ; la somme des fréquences vaut donc π(base).
+/// La distribution n'est en général pas uniforme : en base 10, les chiffres
+/// impairs terminent 8 des 60 termes du cycle et les chiffres pairs 4
+/// seulement. Le vecteur retourné ayant `base` éléments, la fonction est
+/// destinée aux petites bases.
+///
+/// # Panics
+///
+/// Panique si `base` vaut 0.
+///
+pub fn last_digit_frequency(base: u64) -> Vec<u64> {
+    let period = pisano_period(base);
+    let mut frequencies = vec![0u64; base as usize];
+    for residue in fib_mod_iter(base).take(period as usize) {
+        frequencies[residue as usize] += 1;
+    }
+    frequencies
+}
+
+/// Calcule la période de Pisano π(m), période de la suite F(n) mod m.
+///
+/// La suite est itérée modulo `m` en arithmétique `u64`, sans allocation,
+/// jusqu'au retour de la paire (0, 1). Comme π(m) ≤ 6m, le coût est d'au
+/// plus 6m additions modulaires : quelques millisecondes pour m ≈ 10^6,
+/// mais prohibitif pour des modules de l'ordre de 10^12 et au-delà.
+///
+/// Pour `m = 1`, la période vaut 1.
+///
+/// # Panics
+///
+/// Panique si `m` vaut 0.
+///
+pub fn pisano_period(m: u64) -> u64 {
+    assert!(m != 0, "le module doit être non nul");
+    let start = (0, 1 % m);
+    let (mut a, mut b) = start;
+    let mut period = 0;
+    loop {
+        let next = add_mod(a, b, m);
+        a = b;
+        b = next;
+        period += 1;
+        if (a, b) == start {
+            return period;
+        }
+    }
+}
+
+/// Retourne la structure modulaire de la suite de Fibonacci modulo `m` :
+/// (π(m), α(m), k).
+///
+/// * π(m) est la période de Pisano, période de F(n) mod m.
+/// * α(m) est le rang d'apparition, plus petit n >= 1 tel que m divise F(n)
+///   (voir [`rank_of_apparition`]).
+/// * k = π(m) / α(m) est le multiplicateur, qui vaut toujours 1, 2 ou 4.
+///
+/// # Panics
+///
+/// Panique si `m` vaut 0.
+///
+pub fn pisano_structure(m: u64) -> (u64, u64, u64) {
+    let period = pisano_period(m);
+    let rank = rank_of_apparition(m);
+    (period, rank, period / rank)
+}
+
+/// Retourne le rang d'apparition α(m), plus petit index n >= 1 tel que
+/// `m` divise F(n).
+///
+/// Ce rang existe toujours et divise la période de Pisano π(m) ; le calcul
+/// parcourt la suite modulo `m` et coûte donc au plus π(m) <= 6m étapes.
+///
+/// # Panics
+///
+/// Panique si `m` vaut 0.
+///
+pub fn rank_of_apparition(m: u64) -> u64 {
+    fib_mod_iter(m)
+        .skip(1)
+        .position(|residue| residue == 0)
+        .map(|i| i as u64 + 1)
+        .expect("la suite de Fibonacci modulo m repasse toujours par 0")
+}
+
+/// Retourne la table des points d'entrée (p, α(p)) pour chaque nombre
+/// premier p <= `prime_limit`.
+///
+/// Un premier p divise F(n) si et seulement si α(p) divise n : la table
+/// permet donc de prédire quels premiers divisent un F(n) donné. Les
+/// premiers sont obtenus par un crible d'Ératosthène, puis chaque rang par
+/// [`rank_of_apparition`] (au plus p + 1 étapes pour p ≠ 5).
+///
+pub fn prime_entry_points(prime_limit: u64) -> Vec<(u64, u64)> {
+    sieve_primes(prime_limit)
+        .into_iter()
+        .map(|p| (p, rank_of_apparition(p)))
+        .collect()
+}
+
+/// Calcule F(n) mod m par "Fast Doubling" sur les résidus, sans
+/// matérialiser F(n).
+pub(crate) fn fibonacci_mod_u64(n: u128, m: u64) -> u64 {
+    assert!(m != 0, "le module doit être non nul");
+    let (mut a, mut b) = (0, 1 % m);
+    for i in (0..128 - n.leading_zeros()).rev() {
+        let twice_b_minus_a = add_mod(add_mod(b, b, m), m - a, m);
+        let c = mul_mod(a, twice_b_minus_a, m);
+        let d = add_mod(mul_mod(a, a, m), mul_mod(b, b, m), m);
+        if (n >> i) & 1 == 1 {
+            a = d;
+            b = add_mod(c, d, m);
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    a
+}
+
+/// Crible d'Ératosthène : retourne les nombres premiers <= `limit`.
+fn sieve_primes(limit: u64) -> Vec<u64> {
+    if limit < 2 {
+        return Vec::new();
+    }
+    let limit = usize::try_from(limit).expect("la borne du crible dépasse la mémoire adressable");
+    let mut is_composite = vec![false; limit + 1];
+    let mut primes = Vec::new();
+    for i in 2..=limit {
+        if is_composite[i] {
+            continue;
+        }
+        primes.push(i as u64);
+        let mut multiple = i * i;
+        while multiple <= limit {
+            is_composite[multiple] = true;
+            multiple += i;
+        }
+    }
+    primes
+}
+
+/// Plus grand commun diviseur par l'algorithme d'Euclide.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// Calcule (a + b) mod m pour des résidus `a, b < m`.
+fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 + b as u128) % m as u128) as u64
+}
+
+/// Calcule (a * b) mod m sans débordement grâce au passage en `u128`.
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// Calcule base^exp mod m par exponentiation rapide.
+fn pow_mod(base: u64, mut exp: u128, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Test de primalité de Miller-Rabin, déterministe sur tout le domaine `u64`.
+fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for &w in &WITNESSES {
+        if n.is_multiple_of(w) {
+            return n == w;
+        }
+    }
+
+    // Décompose n - 1 = d * 2^s avec d impair.
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for &w in &WITNESSES {
+        let mut x = pow_mod(w, d as u128, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Racine carrée modulaire par l'algorithme de Tonelli-Shanks.
+///
+/// Suppose que `p` est un premier impair et que `a` est un résidu
+/// quadratique modulo `p`.
+fn sqrt_mod_prime(a: u64, p: u64) -> u64 {
+    // Décompose p - 1 = q * 2^s avec q impair.
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+
+    // Cas direct p ≡ 3 (mod 4).
+    if s == 1 {
+        return pow_mod(a, ((p + 1) / 4) as u128, p);
+    }
+
+    // Trouve un non-résidu quadratique z.
+    let mut z = 2;
+    while pow_mod(z, ((p - 1) / 2) as u128, p) != p - 1 {
+        z += 1;
+    }
+
+    let mut m = s;
+    let mut c = pow_mod(z, q as u128, p);
+    let mut t = pow_mod(a, q as u128, p);
+    let mut r = pow_mod(a, q.div_ceil(2) as u128, p);
+
+    while t != 1 {
+        // Plus petit i tel que t^(2^i) = 1.
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = mul_mod(t2, t2, p);
+            i += 1;
+        }
+        let b = pow_mod(c, 1u128 << (m - i - 1), p);
+        m = i;
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        r = mul_mod(r, b, p);
+    }
+    r
+}
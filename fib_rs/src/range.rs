@@ -0,0 +1,230 @@
+//! Génération de plages contiguës F(start)..=F(end).
+//!
+//! Le remplissage d'une plage se fait par simples additions à partir d'une
+//! paire de départ obtenue par "Fast Doubling". La variante parallèle
+//! découpe la plage en blocs indépendants, chacun amorcé par son propre
+//! appel au doubling, puis écrit les blocs dans l'ordre.
+
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::mem;
+use std::thread;
+
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use crate::fibonacci_pair;
+
+/// Écrit F(start), F(start+1), ..., F(end) dans `writer`, un nombre décimal
+/// par ligne, de manière séquentielle.
+///
+/// La paire de départ est calculée une seule fois par doubling, puis chaque
+/// terme suivant est obtenu par une addition. Chaque terme est écrit dès
+/// qu'il est calculé : seuls les deux termes courants sont gardés en
+/// mémoire, quelle que soit la longueur de la plage.
+///
+/// # Errors
+///
+/// Propage toute erreur d'écriture de `writer`.
+///
+pub fn write_range<W: Write>(start: u128, end: u128, writer: &mut W) -> io::Result<()> {
+    if start > end {
+        return Ok(());
+    }
+    write_chunk(start, end, writer)
+}
+
+/// Écrit F(start), F(start+1), ..., F(end) dans `writer`, un nombre décimal
+/// par ligne, en calculant les blocs en parallèle.
+///
+/// La plage est découpée en blocs de `chunk_size` indices. Chaque bloc est
+/// amorcé indépendamment par un appel au doubling puis rempli par additions
+/// et converti en décimal sur son propre thread. Au plus un bloc par cœur
+/// disponible est en cours à un instant donné : dès que le plus ancien est
+/// terminé, il est écrit puis libéré, et le calcul du bloc suivant est
+/// lancé. La mémoire utilisée reste ainsi bornée par environ un bloc par
+/// cœur, quelle que soit la longueur de la plage.
+///
+/// Ce découpage échange un peu de travail redondant (un doubling par bloc)
+/// contre du parallélisme ; des blocs plus grands réduisent ce surcoût.
+///
+/// # Errors
+///
+/// Propage toute erreur d'écriture de `writer`.
+///
+/// # Panics
+///
+/// Panique si `chunk_size` vaut 0.
+///
+pub fn write_range_parallel<W: Write>(
+    start: u128,
+    end: u128,
+    chunk_size: usize,
+    writer: &mut W,
+) -> io::Result<()> {
+    assert!(chunk_size > 0, "la taille des blocs doit être non nulle");
+    if start > end {
+        return Ok(());
+    }
+
+    let workers = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_len = chunk_size as u128;
+    let chunk_end = |lo: u128| lo.saturating_add(chunk_len - 1).min(end);
+    // Bornes (lo, hi) des blocs restants, dans l'ordre.
+    let mut bounds = std::iter::successors(Some((start, chunk_end(start))), |&(_, hi)| {
+        (hi < end).then(|| (hi + 1, chunk_end(hi + 1)))
+    });
+
+    thread::scope(|scope| {
+        let spawn = |(lo, hi): (u128, u128)| {
+            scope.spawn(move || {
+                let mut out = Vec::new();
+                write_chunk(lo, hi, &mut out).expect("l'écriture en mémoire n'échoue pas");
+                out
+            })
+        };
+        let mut pending: VecDeque<_> = bounds.by_ref().take(workers).map(spawn).collect();
+        while let Some(handle) = pending.pop_front() {
+            let chunk = handle.join().expect("le thread de calcul a paniqué");
+            writer.write_all(&chunk)?;
+            drop(chunk);
+            if let Some(next) = bounds.next() {
+                pending.push_back(spawn(next));
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Itérateur infini sur F(0), F(1), F(2), ... (ou à partir d'un index
+/// quelconque).
+///
+/// Créé par [`fib_iter`] ou [`fib_iter_from`]. Chaque étape ne coûte qu'une
+/// addition de grands entiers ; l'itérateur ne retourne jamais `None` et
+/// s'utilise avec `.take(k)` ou `.take_while(...)`.
+#[derive(Debug, Clone)]
+pub struct FibonacciIter {
+    a: BigUint,
+    b: BigUint,
+}
+
+impl Iterator for FibonacciIter {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        let next = &self.a + &self.b;
+        let current = mem::replace(&mut self.a, mem::replace(&mut self.b, next));
+        Some(current)
+    }
+}
+
+/// Retourne un itérateur infini sur la suite de Fibonacci, à partir de F(0).
+pub fn fib_iter() -> FibonacciIter {
+    fib_iter_from(0)
+}
+
+/// Retourne un itérateur infini sur F(n), F(n+1), F(n+2), ...
+///
+/// La paire de départ est obtenue par un unique appel à [`fibonacci_pair`] ;
+/// les termes suivants sont produits par additions.
+///
+pub fn fib_iter_from(n: u128) -> FibonacciIter {
+    let (a, b) = fibonacci_pair(n);
+    FibonacciIter { a, b }
+}
+
+/// Retourne `count` valeurs consécutives F(start), F(start+1), ... sous
+/// forme de `f64`, prêtes à être chargées dans une colonne de DataFrame ou
+/// un tableau `ndarray` (via `Array1::from_vec`, sans copie).
+///
+/// Les valeurs sont calculées exactement puis converties : au-delà de
+/// F(78) la conversion perd en précision (mantisse de 53 bits), et à partir
+/// de F(1477) elle dépasse la capacité de `f64` et vaut `f64::INFINITY`.
+///
+pub fn fibonacci_column(start: u128, count: usize) -> Vec<f64> {
+    let mut column = Vec::with_capacity(count);
+    if count == 0 {
+        return column;
+    }
+    let (mut a, mut b) = fibonacci_pair(start);
+    while column.len() < count {
+        let value = a.to_f64().unwrap_or(f64::INFINITY);
+        column.push(value);
+        if value.is_infinite() {
+            // Tous les termes suivants sont eux aussi hors de portée de f64.
+            column.resize(count, f64::INFINITY);
+            break;
+        }
+        let t = &a + &b;
+        a = b;
+        b = t;
+    }
+    column
+}
+
+/// Avance une paire consécutive (F(n), F(n+1)) de `k` positions et retourne
+/// (F(n+k), F(n+k+1)).
+///
+/// Le décalage utilise `k` additions, ce qui est moins coûteux qu'un
+/// recalcul par doubling lorsque `k` est petit (fenêtre glissante mise à
+/// jour de quelques positions). La fonction s'applique à toute paire
+/// (a, b), qu'elle soit ou non issue de la suite de Fibonacci.
+///
+pub fn shift_pair(a: &BigUint, b: &BigUint, k: u32) -> (BigUint, BigUint) {
+    let mut a = a.clone();
+    let mut b = b.clone();
+    for _ in 0..k {
+        let t = &a + &b;
+        a = b;
+        b = t;
+    }
+    (a, b)
+}
+
+/// Retourne F(a), F(a+d), F(a+2d), ..., soit `count` termes pris à
+/// intervalle régulier `d`.
+///
+/// Seules deux passes de doubling sont nécessaires : l'une pour la paire
+/// (F(a), F(a+1)), l'autre pour (F(d), F(d+1)). Chaque saut de `d` positions
+/// applique ensuite la formule d'addition
+/// F(m+d)   = F(m)·F(d+1) + (F(m+1) - F(m))·F(d)
+/// F(m+d+1) = F(m+1)·F(d+1) + F(m)·F(d)
+/// soit quatre multiplications par terme, au lieu d'un doubling complet
+/// par index. Les index a + k·d peuvent dépasser `u128` sans difficulté.
+///
+pub fn fibonacci_arithmetic_progression(a: u128, d: u128, count: usize) -> Vec<BigUint> {
+    let mut terms = Vec::with_capacity(count);
+    if count == 0 {
+        return terms;
+    }
+    let (mut current, mut next) = fibonacci_pair(a);
+    let (step, step_next) = fibonacci_pair(d);
+    loop {
+        terms.push(current.clone());
+        if terms.len() == count {
+            break;
+        }
+        let jumped = &current * &step_next + (&next - &current) * &step;
+        let jumped_next = &next * &step_next + &current * &step;
+        current = jumped;
+        next = jumped_next;
+    }
+    terms
+}
+
+/// Calcule F(lo)..=F(hi) et les écrit dans `writer` en décimal, un par
+/// ligne, au fur et à mesure.
+fn write_chunk<W: Write>(lo: u128, hi: u128, writer: &mut W) -> io::Result<()> {
+    let (mut a, mut b) = fibonacci_pair(lo);
+    let mut k = lo;
+    loop {
+        writeln!(writer, "{}", a)?;
+        if k == hi {
+            return Ok(());
+        }
+        let t = &a + &b;
+        a = b;
+        b = t;
+        k += 1;
+    }
+}
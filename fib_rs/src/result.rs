@@ -0,0 +1,35 @@
+//! Résultat structuré d'un calcul de F(n), pour une consommation par
+//! programme.
+
+use alloc::format;
+use alloc::string::String;
+
+/// Résultat d'un calcul de F(n), tel qu'émis par l'option `--json` du
+/// binaire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FibResult {
+    /// L'index n.
+    pub index: u128,
+    /// Nombre de chiffres de `value`, dans la base de `value`.
+    pub digits: u64,
+    /// F(n) écrit dans la base choisie (décimale par défaut).
+    pub value: String,
+    /// Durée du calcul de F(n), en microsecondes (hors conversion).
+    pub elapsed_micros: u128,
+}
+
+impl FibResult {
+    /// Retourne le résultat sous forme d'objet JSON sur une seule ligne :
+    /// `{"index":10,"digits":2,"value":"55","elapsed_micros":3}`.
+    ///
+    /// Les nombres sont écrits tels quels, même au-delà de 2^53 ; `value`
+    /// n'est pas échappé, car il ne contient que des chiffres et des
+    /// lettres.
+    ///
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"index\":{},\"digits\":{},\"value\":\"{}\",\"elapsed_micros\":{}}}",
+            self.index, self.digits, self.value, self.elapsed_micros
+        )
+    }
+}
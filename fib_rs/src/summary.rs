@@ -0,0 +1,128 @@
+//! Vue d'ensemble des propriétés de F(n), calculée sans F(n) si possible.
+
+use crate::golden::{LOG10_PHI, LOG10_SQRT5};
+use crate::modular::fibonacci_mod_u64;
+use crate::{
+    fibonacci_digit_count, fibonacci_fast_doubling_iterative, fibonacci_log10, multiplication_count,
+};
+
+/// Nombre maximal de chiffres retenus en tête et en fin de F(n).
+const SUMMARY_DIGITS: usize = 10;
+
+/// En dessous de cet index, F(n) est calculé en entier : toutes les
+/// propriétés sont alors exactes pour un coût négligeable.
+const SUMMARY_EXACT_LIMIT: u128 = 10_000;
+
+/// Nombre de chiffres de garde calculés au-delà des premiers chiffres
+/// retenus, pour détecter une retenue incertaine.
+const GUARD_DIGITS: u32 = 2;
+
+/// Propriétés de F(n) retournées par [`fibonacci_summary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FibSummary {
+    /// L'index n.
+    pub n: u128,
+    /// Nombre de chiffres décimaux de F(n), saturé à `u64::MAX`.
+    pub digit_count: u64,
+    /// Nombre de bits de F(n) (0 pour F(0)), saturé à `u64::MAX`.
+    pub bit_length: u64,
+    /// Premiers chiffres décimaux de F(n) (au plus 10), ou `None` si la
+    /// précision de `f64` ne permet d'en garantir aucun.
+    pub leading_digits: Option<String>,
+    /// Derniers chiffres décimaux de F(n) (au plus 10), toujours exacts.
+    pub last_digits: String,
+    /// `true` si F(n) est pair, c'est-à-dire si 3 divise n.
+    pub is_even: bool,
+    /// Nombre de multiplications de grands entiers du calcul de F(n).
+    pub multiplication_count: u64,
+}
+
+/// Retourne un résumé des propriétés de F(n) : nombres de chiffres et de
+/// bits, premiers et derniers chiffres, parité et coût du calcul.
+///
+/// Pour n < 10 000, F(n) est calculé et toutes les propriétés sont exactes.
+/// Au-delà, aucun grand entier n'est construit (sauf dans le cas rare où
+/// [`fibonacci_digit_count`] doit vérifier exactement), ce qui permet de
+/// résumer F(n) pour n'importe quel index `u128` :
+///
+/// - le nombre de chiffres est celui de [`fibonacci_digit_count`] ; le
+///   nombre de bits découle de la formule de Binet en `f64` et peut
+///   différer d'une unité lorsque log2(F(n)) est très proche d'un entier ;
+///   tous deux sont saturés à `u64::MAX` pour les index astronomiques ;
+/// - les premiers chiffres découlent de la partie fractionnaire de
+///   log10(F(n)), calculée avec deux chiffres de garde : seuls ceux que la
+///   précision de `f64` garantit encore, retenues comprises, sont retenus,
+///   soit de moins en moins à mesure que n grandit, et aucun au-delà de
+///   n ≈ 10^15 ;
+/// - les derniers chiffres sont exacts, calculés par doubling modulo 10^10 ;
+/// - la parité et le nombre de multiplications sont exacts.
+///
+pub fn fibonacci_summary(n: u128) -> FibSummary {
+    let is_even = n.is_multiple_of(3);
+    let multiplication_count = multiplication_count(n);
+
+    if n < SUMMARY_EXACT_LIMIT {
+        let value = fibonacci_fast_doubling_iterative(n);
+        let decimal = value.to_string();
+        let tail_start = decimal.len().saturating_sub(SUMMARY_DIGITS);
+        return FibSummary {
+            n,
+            digit_count: decimal.len() as u64,
+            bit_length: value.bits(),
+            leading_digits: Some(decimal[..decimal.len().min(SUMMARY_DIGITS)].to_string()),
+            last_digits: decimal[tail_start..].to_string(),
+            is_even,
+            multiplication_count,
+        };
+    }
+
+    let log10 = fibonacci_log10(n);
+    let digit_count = fibonacci_digit_count(n);
+    let log2 = (n as f64 * LOG10_PHI - LOG10_SQRT5) / std::f64::consts::LOG10_2;
+    // La conversion `as` sature à u64::MAX pour les index astronomiques.
+    let bit_length = (log2.floor() as u64).saturating_add(1);
+
+    let leading_digits = leading_digits_from_log10(log10);
+
+    let modulus = 10u64.pow(SUMMARY_DIGITS as u32);
+    let last_digits = format!(
+        "{:0width$}",
+        fibonacci_mod_u64(n, modulus),
+        width = SUMMARY_DIGITS
+    );
+
+    FibSummary {
+        n,
+        digit_count,
+        bit_length,
+        leading_digits,
+        last_digits,
+        is_even,
+        multiplication_count,
+    }
+}
+
+/// Retourne les premiers chiffres décimaux d'un nombre dont log10 vaut
+/// `log10` (au plus 10), ou `None` si aucun n'est garanti.
+///
+/// L'erreur absolue sur `log10` est bornée par quelques unités du dernier
+/// bit de sa valeur ; elle se reporte sur la mantisse 10^fract(log10) en
+/// erreur relative. Pour chaque longueur candidate, de la plus grande à la
+/// plus petite, la mantisse est calculée avec deux chiffres de garde : la
+/// longueur est retenue si les deux bornes de l'intervalle d'erreur donnent
+/// le même préfixe, c'est-à-dire si aucune retenue ne peut le modifier
+/// (chiffres suivants en …999 ou …000).
+fn leading_digits_from_log10(log10: f64) -> Option<String> {
+    let log10_error = 4.0 * f64::EPSILON * log10.abs().max(1.0);
+    let relative_error = log10_error * std::f64::consts::LN_10 + 4.0 * f64::EPSILON;
+    let fract = log10.fract();
+    let guard = 10f64.powi(GUARD_DIGITS as i32);
+    (1..=SUMMARY_DIGITS as u32).rev().find_map(|count| {
+        let scaled = 10f64.powf(fract + (count - 1 + GUARD_DIGITS) as f64);
+        let delta = scaled * relative_error;
+        let low = ((scaled - delta) / guard).floor();
+        let high = ((scaled + delta) / guard).floor();
+        let in_range = low >= 10f64.powi(count as i32 - 1) && high < 10f64.powi(count as i32);
+        (low == high && in_range).then(|| (low as u64).to_string())
+    })
+}
@@ -0,0 +1,39 @@
+//! Suite de tests pour la mesure du pic de mémoire (fonctionnalité
+//! `track-alloc`).
+#![cfg(feature = "track-alloc")]
+
+use fib_rs::{fibonacci_fast_doubling_iterative, measure_peak, TrackingAllocator};
+
+#[global_allocator]
+static GLOBAL: TrackingAllocator = TrackingAllocator;
+
+#[test]
+fn test_measure_peak_covers_result() {
+    let (result, peak) = measure_peak(|| fibonacci_fast_doubling_iterative(100_000));
+    // Le résultat lui-même occupe au moins bits / 8 octets.
+    assert!(peak as u64 >= result.bits() / 8);
+}
+
+#[test]
+fn test_measure_peak_grows_with_index() {
+    let (small, small_peak) = measure_peak(|| fibonacci_fast_doubling_iterative(10_000));
+    drop(small);
+    let (large, large_peak) = measure_peak(|| fibonacci_fast_doubling_iterative(200_000));
+    drop(large);
+    assert!(large_peak > small_peak);
+}
+
+#[test]
+fn test_measure_peak_concurrent_calls() {
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let (result, peak) = measure_peak(|| fibonacci_fast_doubling_iterative(100_000));
+                assert!(peak as u64 >= result.bits() / 8);
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,192 @@
+//! Suite de tests pour les applications concrètes de la suite de Fibonacci.
+#![cfg(feature = "std")]
+
+use std::f64::consts::TAU;
+
+use fib_rs::{
+    domino_tilings, fibonacci_base_add, fibonacci_clock, fibonacci_weighted_average,
+    golden_angle_points, square_tilings,
+};
+use num_bigint::BigUint;
+
+/// Dénombre par force brute les compositions de `n` en parts 1 et 2.
+fn count_compositions(n: u32) -> u64 {
+    match n {
+        0 | 1 => 1,
+        _ => count_compositions(n - 1) + count_compositions(n - 2),
+    }
+}
+
+#[test]
+fn test_domino_tilings_small_boards() {
+    // 2×3 : trois verticaux, ou un vertical et deux horizontaux (2 positions).
+    assert_eq!(domino_tilings(3), BigUint::from(3u32));
+    assert_eq!(domino_tilings(0), BigUint::from(1u32));
+    assert_eq!(domino_tilings(1), BigUint::from(1u32));
+    assert_eq!(domino_tilings(4), BigUint::from(5u32));
+    assert_eq!(domino_tilings(10), BigUint::from(89u32));
+}
+
+#[test]
+fn test_tilings_match_brute_force() {
+    for n in 0..25 {
+        let expected = BigUint::from(count_compositions(n));
+        assert_eq!(square_tilings(n), expected, "n={n}");
+        assert_eq!(domino_tilings(n), expected, "n={n}");
+    }
+}
+
+#[test]
+fn test_golden_angle_points_spacing() {
+    let golden_angle = 137.507_764_050_037_85_f64.to_radians();
+    let points = golden_angle_points(500, 2.0);
+    assert_eq!(points.len(), 500);
+    assert_eq!(points[0], (0.0, 0.0));
+
+    for (k, pair) in points.windows(2).enumerate().skip(1) {
+        let (x0, y0) = pair[0];
+        let (x1, y1) = pair[1];
+        let delta = (y1.atan2(x1) - y0.atan2(x0)).rem_euclid(TAU);
+        assert!((delta - golden_angle).abs() < 1e-9, "k={k}");
+    }
+}
+
+#[test]
+fn test_golden_angle_points_radius() {
+    for (k, (x, y)) in golden_angle_points(100, 3.5).into_iter().enumerate() {
+        let expected = 3.5 * (k as f64).sqrt();
+        assert!((x.hypot(y) - expected).abs() < 1e-9, "k={k}");
+    }
+    assert!(golden_angle_points(0, 1.0).is_empty());
+}
+
+/// Encode `value` en représentation de Zeckendorf (chiffre i <-> F(i+2)).
+fn zeckendorf(mut value: u64) -> Vec<bool> {
+    let mut weights = vec![1u64, 2];
+    while *weights.last().unwrap() <= value {
+        let next = weights[weights.len() - 1] + weights[weights.len() - 2];
+        weights.push(next);
+    }
+    let mut digits = vec![false; weights.len()];
+    for (i, &w) in weights.iter().enumerate().rev() {
+        if w <= value {
+            digits[i] = true;
+            value -= w;
+        }
+    }
+    while digits.last() == Some(&false) {
+        digits.pop();
+    }
+    digits
+}
+
+/// Relit une représentation en base de Fibonacci.
+fn decode(digits: &[bool]) -> u64 {
+    let (mut weight, mut next) = (1u64, 2u64);
+    let mut value = 0;
+    for &d in digits {
+        if d {
+            value += weight;
+        }
+        (weight, next) = (next, weight + next);
+    }
+    value
+}
+
+#[test]
+fn test_fibonacci_base_add_many_pairs() {
+    for x in 0..150u64 {
+        for y in 0..150u64 {
+            let sum = fibonacci_base_add(&zeckendorf(x), &zeckendorf(y));
+            assert_eq!(decode(&sum), x + y, "x={x}, y={y}");
+            assert_eq!(sum, zeckendorf(x + y), "x={x}, y={y}");
+        }
+    }
+}
+
+#[test]
+fn test_fibonacci_base_add_canonical_form() {
+    // 4 = 3 + 1 s'écrit 101 ; 4 + 4 = 8 s'écrit 10000.
+    let four = [true, false, true];
+    assert_eq!(
+        fibonacci_base_add(&four, &four),
+        [false, false, false, false, true]
+    );
+    assert!(fibonacci_base_add(&[], &[]).is_empty());
+    // Entrée non canonique : 1 + 2 écrit 11, plus 0.
+    assert_eq!(fibonacci_base_add(&[true, true], &[]), [false, false, true]);
+
+    let big = fibonacci_base_add(&zeckendorf(1_000_000_007), &zeckendorf(999_999_937));
+    assert!(big.windows(2).all(|w| !(w[0] && w[1])));
+    assert_eq!(decode(&big), 1_000_000_007 + 999_999_937);
+}
+
+#[test]
+fn test_fibonacci_clock_known_times() {
+    // 10:25 : heures 5 + 3 + 2, minutes 5 (25 / 5).
+    assert_eq!(fibonacci_clock(10, 25), [0, 0, 1, 1, 3]);
+    // 12:00 et minuit : toutes les heures, aucune minute.
+    assert_eq!(fibonacci_clock(12, 0), [1, 1, 1, 1, 1]);
+    assert_eq!(fibonacci_clock(0, 4), [1, 1, 1, 1, 1]);
+    // 1:05 : le carré 1 compte à la fois pour l'heure et les minutes.
+    assert_eq!(fibonacci_clock(1, 5), [0, 3, 0, 0, 0]);
+    // 15:59 = 3:55 : heures 3, minutes 11 = 5 + 3 + 2 + 1.
+    assert_eq!(fibonacci_clock(15, 59), [0, 2, 2, 3, 2]);
+}
+
+#[test]
+fn test_fibonacci_clock_encodes_every_time() {
+    const SIDES: [u32; 5] = [1, 1, 2, 3, 5];
+    for hour in 0..24u8 {
+        for minute in 0..60u8 {
+            let state = fibonacci_clock(hour, minute);
+            let sum = |flag: u8| -> u32 {
+                (0..5)
+                    .filter(|&i| state[i] & flag != 0)
+                    .map(|i| SIDES[i])
+                    .sum()
+            };
+            let expected_hours = if hour % 12 == 0 { 12 } else { hour % 12 };
+            assert_eq!(sum(1), expected_hours as u32, "{hour}:{minute}");
+            assert_eq!(sum(2), (minute / 5) as u32, "{hour}:{minute}");
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_fibonacci_clock_rejects_invalid_minute() {
+    fibonacci_clock(3, 60);
+}
+
+#[test]
+fn test_fibonacci_weighted_average_hand_computed() {
+    // Poids 1, 1, 2, 3 : (1·10 + 1·20 + 2·30 + 3·40) / 7 = 210 / 7.
+    assert_eq!(fibonacci_weighted_average(&[10.0, 20.0, 30.0, 40.0]), 30.0);
+    assert_eq!(fibonacci_weighted_average(&[4.5]), 4.5);
+    // Poids 1, 1, 2, 3, 5 : (0 + 0 + 0 + 0 + 5·12) / 12.
+    assert_eq!(fibonacci_weighted_average(&[0.0, 0.0, 0.0, 0.0, 12.0]), 5.0);
+}
+
+#[test]
+fn test_fibonacci_weighted_average_empty_is_nan() {
+    assert!(fibonacci_weighted_average(&[]).is_nan());
+}
+
+#[test]
+fn test_fibonacci_weighted_average_long_series() {
+    // Une série constante a pour moyenne cette constante, même au-delà de
+    // F(1476), le plus grand nombre de Fibonacci représentable en f64.
+    let constant = vec![3.25; 5_000];
+    let average = fibonacci_weighted_average(&constant);
+    assert!((average - 3.25).abs() < 1e-12, "moyenne={average}");
+
+    // Les derniers échantillons dominent : le dernier pèse 1/φ² du total.
+    let mut series = vec![0.0; 5_000];
+    series[4_999] = 1.0;
+    let average = fibonacci_weighted_average(&series);
+    assert!(
+        (average - 0.381_966_011_250_105).abs() < 1e-12,
+        "moyenne={average}"
+    );
+}
@@ -0,0 +1,69 @@
+//! Suite de tests pour le cache partagé de valeurs F(n).
+#![cfg(feature = "std")]
+
+use std::sync::Arc;
+use std::thread;
+
+use fib_rs::{fibonacci_fast_doubling_iterative, FibCache};
+
+#[test]
+fn test_cache_returns_correct_values() {
+    let cache = FibCache::new(16);
+    assert!(cache.is_empty());
+    for n in [0u128, 1, 2, 10, 1_000] {
+        assert_eq!(cache.get(n), fibonacci_fast_doubling_iterative(n));
+        // Second accès : valeur servie depuis le cache.
+        assert_eq!(cache.get(n), fibonacci_fast_doubling_iterative(n));
+    }
+    assert_eq!(cache.len(), 5);
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_cache_evicts_least_recently_used() {
+    let cache = FibCache::new(2);
+    cache.get(1);
+    cache.get(2);
+    // Rafraîchit 1 : c'est 2 qui doit être évincé.
+    cache.get(1);
+    cache.get(3);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains(1));
+    assert!(!cache.contains(2));
+    assert!(cache.contains(3));
+}
+
+#[test]
+fn test_cache_zero_capacity_stores_nothing() {
+    let cache = FibCache::new(0);
+    assert_eq!(cache.get(12), 144u32.into());
+    assert!(cache.is_empty());
+    assert_eq!(cache.capacity(), 0);
+}
+
+#[test]
+fn test_cache_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<FibCache>();
+}
+
+#[test]
+fn test_cache_concurrent_gets_are_consistent() {
+    let cache = Arc::new(FibCache::new(8));
+    let handles: Vec<_> = (0..8u128)
+        .map(|t| {
+            let cache = Arc::clone(&cache);
+            thread::spawn(move || {
+                for i in 0..200u128 {
+                    let n = (i * 7 + t) % 20 * 100;
+                    assert_eq!(cache.get(n), fibonacci_fast_doubling_iterative(n));
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert!(cache.len() <= cache.capacity());
+}
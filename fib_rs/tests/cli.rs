@@ -0,0 +1,294 @@
+//! Suite de tests de bout en bout pour le binaire `fib_rs`.
+#![cfg(feature = "std")]
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+use fib_rs::fibonacci_fast_doubling_iterative;
+
+/// Exécute le binaire compilé avec les arguments donnés.
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_fib_rs"))
+        .args(args)
+        .output()
+        .expect("impossible de lancer le binaire fib_rs")
+}
+
+/// Chemin de fichier temporaire propre à ce test et à ce processus.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("fib_rs_{}_{}", std::process::id(), name))
+}
+
+#[test]
+fn test_transform_writes_one_value_per_valid_line() {
+    let input = temp_path("transform_input.txt");
+    let output = temp_path("transform_output.txt");
+    fs::write(&input, "10\n0\n\nabc\n100\n-3\n  12  \n").unwrap();
+
+    let result = run(&[
+        "transform",
+        input.to_str().unwrap(),
+        output.to_str().unwrap(),
+    ]);
+    assert!(result.status.success());
+
+    let written = fs::read_to_string(&output).unwrap();
+    let lines: Vec<&str> = written.lines().collect();
+    assert_eq!(
+        lines,
+        ["55", "0", "354224848179261915075", "144"],
+        "les lignes invalides doivent être ignorées"
+    );
+
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(stderr.contains("ligne 4 ignorée"));
+    assert!(stderr.contains("ligne 6 ignorée"));
+
+    fs::remove_file(input).unwrap();
+    fs::remove_file(output).unwrap();
+}
+
+#[test]
+fn test_transform_missing_input_fails() {
+    let output = temp_path("transform_missing_output.txt");
+    let result = run(&[
+        "transform",
+        temp_path("does_not_exist.txt").to_str().unwrap(),
+        output.to_str().unwrap(),
+    ]);
+    assert!(!result.status.success());
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_trace_json_emits_one_object_per_bit() {
+    let n: u128 = 200_000;
+    let result = run(&["--trace-json", &n.to_string()]);
+    assert!(result.status.success());
+
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.first(), Some(&"["));
+    assert_eq!(lines.last(), Some(&"]"));
+
+    let steps = &lines[1..lines.len() - 1];
+    assert_eq!(steps.len() as u32, 128 - n.leading_zeros());
+    assert_eq!(
+        steps[0],
+        "  {\"bit\": 17, \"op\": \"double_advance\", \"a_bits\": 1, \"b_bits\": 1},"
+    );
+    // F(200000) compte 138 848 bits.
+    assert!(steps.last().unwrap().contains("\"a_bits\": 138848,"));
+    assert!(!steps.last().unwrap().ends_with(','));
+}
+
+#[test]
+fn test_trace_json_zero_is_empty_array() {
+    let result = run(&["--trace-json", "0"]);
+    assert!(result.status.success());
+    assert_eq!(String::from_utf8(result.stdout).unwrap(), "[\n]\n");
+}
+
+#[test]
+fn test_format_balanced_ternary() {
+    // F(10) = 55 = 81 - 27 + 0 + 0 + 1 = 1T001 en ternaire équilibré.
+    let result = run(&["--format", "balanced-ternary", "10"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("Nombre total de chiffres en ternaire équilibré: 5"));
+    assert!(stdout.contains("Résultat: 1T001"));
+}
+
+#[test]
+fn test_format_unknown_value_fails() {
+    let result = run(&["--format", "roman", "10"]);
+    assert!(!result.status.success());
+    assert!(String::from_utf8_lossy(&result.stderr).contains("Format 'roman' inconnu"));
+}
+
+#[test]
+fn test_summary_flag_prints_properties() {
+    let result = run(&["--summary", "12"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("Résumé de F(12) :"));
+    assert!(stdout.contains("Chiffres décimaux : 3"));
+    assert!(stdout.contains("Longueur en bits : 8"));
+    assert!(stdout.contains("Premiers chiffres : 144..."));
+    assert!(stdout.contains("Parité : pair"));
+    assert!(!stdout.contains("Calcul terminé"));
+}
+
+#[test]
+fn test_summary_flag_handles_huge_index() {
+    let result = run(&[&u128::MAX.to_string(), "--summary"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("Multiplications : 384"));
+    assert!(stdout.contains("hors de la précision de f64"));
+}
+
+#[test]
+fn test_multiple_indices_and_ranges() {
+    let result = run(&["10", "3..5", "12"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    let results: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("Résultat: "))
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            "Résultat: 55",
+            "Résultat: 2",
+            "Résultat: 3",
+            "Résultat: 5",
+            "Résultat: 144"
+        ]
+    );
+    for n in [10, 3, 4, 5, 12] {
+        assert!(
+            stdout.contains(&format!("Calcul de Fibonacci F({n})")),
+            "n={n}"
+        );
+    }
+}
+
+#[test]
+fn test_bad_argument_reported_without_stopping() {
+    let result = run(&["7", "abc", "9..8", "8"]);
+    assert!(!result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("Résultat: 13"));
+    assert!(stdout.contains("Résultat: 21"));
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(stderr.contains("'abc'"));
+    assert!(stderr.contains("'9..8'"));
+}
+
+#[test]
+fn test_errors_are_reported_in_french() {
+    let result = run(&["abc"]);
+    assert_eq!(result.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert!(stderr.starts_with("Erreur : L'argument 'abc'"), "{stderr}");
+
+    let result = run(&[]);
+    assert_eq!(result.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&result.stderr).starts_with("Usage:"));
+}
+
+#[test]
+fn test_format_hex_and_bin() {
+    // F(12) = 144 = 0x90 = 0b10010000.
+    let result = run(&["--format", "hex", "12"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("Nombre total de chiffres hexadécimaux: 2"));
+    assert!(stdout.contains("Résultat: 90"));
+
+    let result = run(&["--format", "bin", "12"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("Nombre total de chiffres binaires: 8"));
+    assert!(stdout.contains("Résultat: 10010000"));
+}
+
+#[test]
+fn test_format_bin_preview_is_truncated() {
+    // F(1000) compte 694 bits : seul un aperçu est affiché.
+    let result = run(&["--format", "bin", "1000"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("Nombre total de chiffres binaires: 694"));
+    assert!(stdout.contains("Début: 1"));
+    assert!(!stdout.contains("Résultat:"));
+}
+
+#[test]
+fn test_output_writes_full_results() {
+    let output = temp_path("output_full.txt");
+    let result = run(&["--output", output.to_str().unwrap(), "1000", "10"]);
+    assert!(result.status.success());
+
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("Nombre total de chiffres décimaux: 209"));
+    assert!(stdout.contains("Calcul terminé en"));
+    assert!(!stdout.contains("Début:"));
+
+    let written = fs::read_to_string(&output).unwrap();
+    let expected = format!("{}\n55\n", fibonacci_fast_doubling_iterative(1000));
+    assert_eq!(written, expected);
+    fs::remove_file(&output).unwrap();
+}
+
+#[test]
+fn test_output_unwritable_path_fails() {
+    let missing_dir = temp_path("no_such_dir").join("out.txt");
+    let result = run(&["--output", missing_dir.to_str().unwrap(), "10"]);
+    assert!(!result.status.success());
+    assert!(String::from_utf8_lossy(&result.stderr).contains("Erreur d'entrée/sortie"));
+}
+
+#[test]
+fn test_algo_matrix_selects_matrix_exponentiation() {
+    let result = run(&["--algo", "matrix", "100"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.contains("avec l'algorithme d'exponentiation matricielle"));
+    assert!(stdout.contains("Résultat: 354224848179261915075"));
+}
+
+#[test]
+fn test_json_single_index_prints_object() {
+    let result = run(&["--json", "10"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.starts_with(r#"{"index":10,"digits":2,"value":"55","elapsed_micros":"#));
+    assert!(stdout.ends_with("}\n"));
+}
+
+#[test]
+fn test_json_multiple_indices_print_array_in_radix() {
+    // F(12) = 144 = 0x90, F(13) = 233 = 0xe9.
+    let result = run(&["--json", "--format", "hex", "12..13"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert!(stdout.starts_with(r#"[{"index":12,"digits":2,"value":"90","#));
+    assert!(stdout.contains(r#"},{"index":13,"digits":2,"value":"e9","#));
+    assert!(stdout.ends_with("}]\n"));
+}
+
+#[cfg(feature = "track-alloc")]
+#[test]
+fn test_memscaling_csv_output() {
+    let result = run(&["memscaling", "50000", "10000"]);
+    assert!(result.status.success());
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("n,peak_bytes,digits"));
+
+    let rows: Vec<(u128, u64, u64)> = lines
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            assert_eq!(fields.len(), 3, "ligne {line:?}");
+            (
+                fields[0].parse().unwrap(),
+                fields[1].parse().unwrap(),
+                fields[2].parse().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(rows.len(), 5);
+    for (i, &(n, _, digits)) in rows.iter().enumerate() {
+        assert_eq!(n, 10_000 * (i as u128 + 1));
+        let expected = fibonacci_fast_doubling_iterative(n).to_string().len() as u64;
+        assert_eq!(digits, expected, "n={n}");
+    }
+    assert!(
+        rows.windows(2).all(|w| w[0].1 <= w[1].1),
+        "pic de mémoire décroissant : {rows:?}"
+    );
+}
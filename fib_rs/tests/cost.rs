@@ -0,0 +1,101 @@
+//! Suite de tests pour l'estimation du coût de calcul.
+#![cfg(feature = "std")]
+
+use fib_rs::{
+    fibonacci_fast_doubling_iterative, fibonacci_traced, index_cost_score, multiplication_count,
+    operation_counts, TraceOp,
+};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Réplique instrumentée de la boucle "Fast Doubling" qui compte les
+/// multiplications de grands entiers effectivement réalisées.
+fn instrumented_multiplications(n: u128) -> (BigUint, u64) {
+    let mut count = 0;
+    let mut mul = |x: &BigUint, y: &BigUint| {
+        count += 1;
+        x * y
+    };
+
+    let mut a = BigUint::zero();
+    let mut b = BigUint::one();
+    if n != 0 {
+        let msb_index = 127 - n.leading_zeros();
+        for i in (0..=msb_index).rev() {
+            let c = mul(&a, &(&b * 2u32 - &a));
+            let d = mul(&a, &a) + mul(&b, &b);
+            a = c;
+            b = d;
+            if (n >> i) & 1 == 1 {
+                let t = &a + &b;
+                a = b;
+                b = t;
+            }
+        }
+    }
+    (a, count)
+}
+
+#[test]
+fn test_multiplication_count_matches_instrumented_run() {
+    for n in (0..300u128).chain([1 << 16, (1 << 16) - 1, 100_003]) {
+        let (value, count) = instrumented_multiplications(n);
+        assert_eq!(value, fibonacci_fast_doubling_iterative(n), "n={n}");
+        assert_eq!(multiplication_count(n), count, "n={n}");
+    }
+}
+
+#[test]
+fn test_multiplication_count_powers_of_two() {
+    assert_eq!(multiplication_count(0), 0);
+    for k in 1..128u32 {
+        assert_eq!(multiplication_count((1u128 << k) - 1), 3 * k as u64);
+        assert_eq!(multiplication_count(1u128 << k), 3 * (k as u64 + 1));
+    }
+    assert_eq!(multiplication_count(u128::MAX), 3 * 128);
+}
+
+#[test]
+fn test_operation_counts_matches_traced_loop() {
+    for n in (0..300u128).chain([1 << 16, (1 << 16) - 1, 0xBEEF]) {
+        let (mut doublings, mut advances) = (0, 0);
+        fibonacci_traced(n, |step| {
+            doublings += 1;
+            if step.op == TraceOp::DoubleAdvance {
+                advances += 1;
+            }
+        });
+        assert_eq!(operation_counts(n), (doublings, advances), "n={n}");
+    }
+}
+
+#[test]
+fn test_operation_counts_examples() {
+    assert_eq!(operation_counts(0), (0, 0));
+    assert_eq!(operation_counts(1), (1, 1));
+    assert_eq!(operation_counts(100), (7, 3));
+    assert_eq!(operation_counts(u128::MAX), (128, 128));
+}
+
+#[test]
+fn test_index_cost_score_known_values() {
+    assert_eq!(index_cost_score(0), 0.0);
+    assert_eq!(index_cost_score(1), 1.5);
+    // 100 = 0b1100100 : 7 bits dont 3 à 1.
+    assert_eq!(index_cost_score(100), 8.5);
+}
+
+#[test]
+fn test_index_cost_score_orders_powers_of_two_first() {
+    for k in 4..128u32 {
+        let power = 1u128 << k;
+        assert!(
+            index_cost_score(power) < index_cost_score(power - 1),
+            "k={k}"
+        );
+    }
+
+    let mut batch = vec![255u128, 256, 1_000, 1_024, 1_023];
+    batch.sort_by(|x, y| index_cost_score(*x).total_cmp(&index_cost_score(*y)));
+    assert_eq!(batch, vec![256, 1_024, 255, 1_000, 1_023]);
+}
@@ -0,0 +1,238 @@
+//! Suite de tests pour les représentations et chiffres de F(n).
+#![cfg(feature = "std")]
+
+use fib_rs::{
+    digit_growth_ratio, digits_added_between, digits_per_step, fibonacci_decimal_digits,
+    fibonacci_digit_count, fibonacci_fast_doubling_iterative, fibonacci_log10, fibonacci_multibase,
+    fibonacci_popcount, to_balanced_ternary, to_decimal_string,
+};
+use num_bigint::{BigInt, BigUint};
+
+#[test]
+fn test_popcount_small_values() {
+    // F(0)=0, F(1)=1, F(5)=5=0b101, F(7)=13=0b1101, F(12)=144=0b10010000
+    assert_eq!(fibonacci_popcount(0), 0);
+    assert_eq!(fibonacci_popcount(1), 1);
+    assert_eq!(fibonacci_popcount(5), 2);
+    assert_eq!(fibonacci_popcount(7), 3);
+    assert_eq!(fibonacci_popcount(12), 2);
+}
+
+#[test]
+fn test_popcount_matches_binary_string() {
+    for n in (0..200u128).chain([1_000, 4_321]) {
+        let binary = fibonacci_fast_doubling_iterative(n).to_str_radix(2);
+        let expected = binary.bytes().filter(|&c| c == b'1').count() as u64;
+        assert_eq!(fibonacci_popcount(n), expected, "n={n}");
+    }
+}
+
+#[test]
+fn test_log10_matches_digit_count() {
+    assert_eq!(fibonacci_log10(0), f64::NEG_INFINITY);
+    assert_eq!(fibonacci_log10(1), 0.0);
+    for n in (1..300u128).chain([1_000, 10_000, 54_321]) {
+        let digits = fibonacci_fast_doubling_iterative(n).to_string().len();
+        assert_eq!(fibonacci_log10(n).floor() as usize + 1, digits, "n={n}");
+    }
+}
+
+#[test]
+fn test_decimal_digits_values() {
+    assert_eq!(fibonacci_decimal_digits(0), [0]);
+    assert_eq!(fibonacci_decimal_digits(1), [1]);
+    assert_eq!(fibonacci_decimal_digits(12), [1, 4, 4]);
+    assert_eq!(fibonacci_decimal_digits(20), [6, 7, 6, 5]);
+}
+
+#[test]
+fn test_decimal_digits_reconstruct_value() {
+    for n in (0..150u128).chain([1_000, 9_999]) {
+        let expected = fibonacci_fast_doubling_iterative(n);
+        let digits = fibonacci_decimal_digits(n);
+        assert_eq!(digits.len(), expected.to_string().len(), "n={n}");
+        assert!(digits.iter().all(|&d| d <= 9));
+
+        let value = digits
+            .iter()
+            .fold(BigUint::from(0u32), |acc, &d| acc * 10u32 + d);
+        assert_eq!(value, expected, "n={n}");
+    }
+}
+
+#[test]
+fn test_digit_growth_ratio_small_indices() {
+    assert_eq!(digit_growth_ratio(0), 1.0);
+    assert_eq!(digit_growth_ratio(1), 1.0);
+    // F(20) = 6765 (4 chiffres), F(10) = 55 (2 chiffres).
+    assert_eq!(digit_growth_ratio(10), 2.0);
+    for n in 1..200u128 {
+        let full = fibonacci_fast_doubling_iterative(2 * n).to_string().len() as f64;
+        let half = fibonacci_fast_doubling_iterative(n).to_string().len() as f64;
+        assert_eq!(digit_growth_ratio(n), full / half, "n={n}");
+    }
+}
+
+#[test]
+fn test_digit_growth_ratio_approaches_two() {
+    // Le seul écart vient des arrondis : il est au plus de l'ordre de
+    // 1 / (nombre de chiffres de F(n)) ≈ 1 / (0.209 n).
+    for k in 2..30 {
+        let n = 10u128.pow(k);
+        let gap = (digit_growth_ratio(n) - 2.0).abs();
+        assert!(gap <= 1.0 / (0.2 * n as f64), "k={k}");
+    }
+    assert!((digit_growth_ratio(u128::MAX) - 2.0).abs() < 1e-12);
+}
+
+/// Relit une chaîne en ternaire équilibré (référence pour les tests).
+fn from_balanced_ternary(s: &str) -> BigInt {
+    s.chars().fold(BigInt::from(0), |acc, c| {
+        let digit = match c {
+            'T' => -1,
+            '0' => 0,
+            '1' => 1,
+            _ => panic!("chiffre invalide : {c}"),
+        };
+        acc * 3 + digit
+    })
+}
+
+#[test]
+fn test_balanced_ternary_known_values() {
+    let known = [
+        (0u32, "0"),
+        (1, "1"),
+        (2, "1T"),
+        (3, "10"),
+        (4, "11"),
+        (5, "1TT"),
+        (8, "10T"),
+    ];
+    for (value, expected) in known {
+        assert_eq!(to_balanced_ternary(&BigUint::from(value)), expected);
+    }
+}
+
+#[test]
+fn test_balanced_ternary_round_trip() {
+    for value in (0..500u32).map(BigUint::from).chain(
+        [100u128, 1_000, 12_345]
+            .into_iter()
+            .map(fibonacci_fast_doubling_iterative),
+    ) {
+        let encoded = to_balanced_ternary(&value);
+        assert!(value == BigUint::from(0u32) || encoded.starts_with('1'));
+        assert_eq!(from_balanced_ternary(&encoded), BigInt::from(value));
+    }
+}
+
+#[test]
+fn test_digits_per_step_value() {
+    assert!((digits_per_step() - 0.208_987_640_249_978_73).abs() < 1e-15);
+    let phi = (1.0 + 5f64.sqrt()) / 2.0;
+    assert!((digits_per_step() - phi.log10()).abs() < 1e-15);
+}
+
+#[test]
+fn test_digits_added_between_matches_actual_counts() {
+    let digits = |n: u128| fibonacci_fast_doubling_iterative(n).to_string().len() as i64;
+    let indices = [0u128, 1, 7, 30, 100, 479, 1_000, 4_784, 10_000];
+    for &m in &indices {
+        for &n in &indices {
+            assert_eq!(
+                digits_added_between(m, n),
+                digits(n) - digits(m),
+                "m={m}, n={n}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_fibonacci_multibase_matches_to_str_radix() {
+    let bases = [10, 16, 2, 36, 7];
+    for n in [0u128, 1, 10, 100, 1_000] {
+        let (value, rendered) = fibonacci_multibase(n, &bases);
+        assert_eq!(value, fibonacci_fast_doubling_iterative(n));
+        assert_eq!(rendered.len(), bases.len());
+        for (&base, text) in bases.iter().zip(&rendered) {
+            assert_eq!(*text, value.to_str_radix(base), "n={n}, base={base}");
+        }
+    }
+
+    let (_, rendered) = fibonacci_multibase(12, &[10, 16, 2]);
+    assert_eq!(rendered, vec!["144", "90", "10010000"]);
+    assert!(fibonacci_multibase(5, &[]).1.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_fibonacci_multibase_rejects_base_one() {
+    fibonacci_multibase(10, &[10, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_fibonacci_multibase_rejects_base_above_36() {
+    fibonacci_multibase(10, &[37]);
+}
+
+#[test]
+fn test_fibonacci_digit_count_matches_string_length() {
+    for n in (0..3_000u128).chain([10_000, 54_321]) {
+        let expected = fibonacci_fast_doubling_iterative(n).to_string().len() as u64;
+        assert_eq!(fibonacci_digit_count(n), expected, "n={n}");
+    }
+}
+
+#[test]
+fn test_fibonacci_digit_count_near_power_of_ten() {
+    // log10(F(276908)) dépasse un entier d'environ 1.3e-6 seulement, ce qui
+    // reste hors de la fenêtre de vérification exacte (testée dans le
+    // module `digits`).
+    let n = 276_908;
+    let digits = fibonacci_digit_count(n);
+    let value = fibonacci_fast_doubling_iterative(n);
+    let ten = BigUint::from(10u32);
+    assert!(value >= ten.pow(digits as u32 - 1));
+    assert!(value < ten.pow(digits as u32));
+}
+
+#[test]
+fn test_fibonacci_digit_count_saturates() {
+    assert_eq!(fibonacci_digit_count(u128::MAX), u64::MAX);
+    assert_eq!(fibonacci_digit_count(1_000_000), 208_988);
+}
+
+#[test]
+fn test_to_decimal_string_matches_to_string() {
+    let mut values: Vec<BigUint> = [0u128, 1, 9, 10, 12_345, u128::MAX]
+        .iter()
+        .map(|&v| BigUint::from(v))
+        .collect();
+    for n in [5_000u128, 10_000, 50_000, 200_000] {
+        values.push(fibonacci_fast_doubling_iterative(n));
+    }
+    for k in [999u32, 1_000, 1_001, 4_000, 9_000] {
+        let power = BigUint::from(10u32).pow(k);
+        values.push(&power - 1u32);
+        values.push(power);
+    }
+    for value in values {
+        assert_eq!(to_decimal_string(&value), value.to_string());
+    }
+}
+
+#[test]
+fn test_to_decimal_string_pads_low_halves() {
+    // Les moitiés basses sont presque nulles : la concaténation doit
+    // restituer leurs zéros de tête.
+    let ten = BigUint::from(10u32);
+    for (high, k, low) in [(7u32, 2_000u32, 5u32), (1, 3_000, 0), (42, 8_191, 1)] {
+        let value = BigUint::from(high) * ten.pow(k) + low;
+        let decimal = to_decimal_string(&value);
+        assert_eq!(decimal, value.to_string());
+        assert_eq!(decimal.len(), high.to_string().len() + k as usize);
+    }
+}
@@ -0,0 +1,310 @@
+//! Suite de tests pour les algorithmes de calcul de F(n) de la bibliothèque.
+
+#[cfg(feature = "std")]
+use fib_rs::fibonacci_split;
+use fib_rs::{
+    fibonacci_additive_capped, fibonacci_fast_doubling_big, fibonacci_fast_doubling_iterative,
+    fibonacci_pair, fibonacci_signed, fibonacci_traced, fibonacci_with, fibonacci_with_progress,
+    gibonacci_pair, lucas, Algorithm, FibError, TraceOp,
+};
+#[cfg(feature = "parallel")]
+use fib_rs::{fibonacci_parallel, PARALLEL_THRESHOLD_BITS};
+use num_bigint::{BigInt, BigUint};
+
+// Les premiers termes de la suite de Fibonacci pour les tests.
+const FIBONACCI_TERMS: [u32; 13] = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144];
+
+#[test]
+fn test_fast_doubling_known_values() {
+    for (n, &expected) in FIBONACCI_TERMS.iter().enumerate() {
+        assert_eq!(
+            fibonacci_fast_doubling_iterative(n as u128),
+            BigUint::from(expected)
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_split_matches_direct() {
+    for n in [1u128, 2, 10, 97, 1000, 4096] {
+        let expected = fibonacci_fast_doubling_iterative(n);
+        for split in [0, 1, n / 3, n / 2, n - 1, n] {
+            assert_eq!(fibonacci_split(n, split), expected, "n={n}, split={split}");
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_split_zero_index() {
+    assert_eq!(fibonacci_split(0, 0), BigUint::from(0u32));
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic]
+fn test_split_beyond_index_panics() {
+    fibonacci_split(10, 11);
+}
+
+#[test]
+fn test_additive_capped_matches_fast_doubling() {
+    for n in (0..300u64).chain([1_000, 5_000]) {
+        assert_eq!(
+            fibonacci_additive_capped(n, 5_000),
+            Ok(fibonacci_fast_doubling_iterative(n as u128)),
+            "n={n}"
+        );
+    }
+}
+
+#[test]
+fn test_additive_capped_rejects_beyond_cap() {
+    assert_eq!(
+        fibonacci_additive_capped(1_001, 1_000),
+        Err(FibError::StepLimitExceeded {
+            n: 1_001,
+            max_steps: 1_000
+        })
+    );
+    assert!(fibonacci_additive_capped(u64::MAX, 0).is_err());
+    assert_eq!(fibonacci_additive_capped(0, 0), Ok(BigUint::from(0u32)));
+}
+
+/// Itère naïvement une suite de Fibonacci généralisée jusqu'à l'index `n`.
+fn naive_gibonacci(n: u128, a0: i64, a1: i64) -> (BigInt, BigInt) {
+    let (mut a, mut b) = (BigInt::from(a0), BigInt::from(a1));
+    for _ in 0..n {
+        let t = &a + &b;
+        a = b;
+        b = t;
+    }
+    (a, b)
+}
+
+#[test]
+fn test_gibonacci_pair_matches_naive_iteration() {
+    // Fibonacci, Lucas, et des graines signées quelconques.
+    for (a0, a1) in [(0, 1), (2, 1), (-7, 3), (5, -12), (0, 0)] {
+        for n in 0..120u128 {
+            assert_eq!(
+                gibonacci_pair(n, &BigInt::from(a0), &BigInt::from(a1)),
+                naive_gibonacci(n, a0, a1),
+                "n={n}, graines=({a0}, {a1})"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_gibonacci_pair_fibonacci_and_lucas() {
+    let (f, _) = gibonacci_pair(100, &BigInt::from(0), &BigInt::from(1));
+    assert_eq!(f, BigInt::from(fibonacci_fast_doubling_iterative(100)));
+
+    // L(10) = 123, L(11) = 199
+    let lucas = gibonacci_pair(10, &BigInt::from(2), &BigInt::from(1));
+    assert_eq!(lucas, (BigInt::from(123), BigInt::from(199)));
+}
+
+#[test]
+fn test_traced_reports_one_step_per_bit() {
+    for n in [1u128, 2, 5, 12, 1_000, 65_535, 65_536] {
+        let mut steps = Vec::new();
+        let result = fibonacci_traced(n, |step| steps.push(*step));
+        assert_eq!(result, fibonacci_fast_doubling_iterative(n));
+
+        let msb_index = 127 - n.leading_zeros();
+        let bits: Vec<u32> = steps.iter().map(|s| s.bit).collect();
+        assert_eq!(bits, (0..=msb_index).rev().collect::<Vec<_>>(), "n={n}");
+        for step in &steps {
+            let expected = if (n >> step.bit) & 1 == 1 {
+                TraceOp::DoubleAdvance
+            } else {
+                TraceOp::Double
+            };
+            assert_eq!(step.op, expected, "n={n}, bit={}", step.bit);
+        }
+
+        // La dernière étape décrit (F(n), F(n+1)).
+        let last = steps.last().unwrap();
+        assert_eq!(last.a_bits, result.bits());
+        assert_eq!(last.b_bits, fibonacci_fast_doubling_iterative(n + 1).bits());
+    }
+}
+
+#[test]
+fn test_traced_zero_has_no_steps() {
+    let mut count = 0;
+    assert_eq!(fibonacci_traced(0, |_| count += 1), BigUint::from(0u32));
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_pair_returns_consecutive_terms() {
+    assert_eq!(
+        fibonacci_pair(0),
+        (BigUint::from(0u32), BigUint::from(1u32))
+    );
+    for n in [1u128, 2, 11, 100, 4_095, 4_096] {
+        let (current, next) = fibonacci_pair(n);
+        assert_eq!(current, fibonacci_fast_doubling_iterative(n), "n={n}");
+        assert_eq!(next, fibonacci_fast_doubling_iterative(n + 1), "n={n}");
+    }
+}
+
+#[test]
+fn test_signed_negative_indices() {
+    // F(-1) .. F(-10)
+    let expected = [1, -1, 2, -3, 5, -8, 13, -21, 34, -55];
+    for (k, &value) in expected.iter().enumerate() {
+        let n = -(k as i128 + 1);
+        assert_eq!(fibonacci_signed(n), BigInt::from(value), "n={n}");
+    }
+}
+
+#[test]
+fn test_signed_matches_unsigned_and_recurrence() {
+    for n in 0..=50i128 {
+        assert_eq!(
+            fibonacci_signed(n),
+            BigInt::from(fibonacci_fast_doubling_iterative(n as u128))
+        );
+    }
+    // F(n+1) = F(n) + F(n-1) reste valable de part et d'autre de 0.
+    for n in -60..60i128 {
+        assert_eq!(
+            fibonacci_signed(n + 1),
+            fibonacci_signed(n) + fibonacci_signed(n - 1),
+            "n={n}"
+        );
+    }
+}
+
+#[test]
+fn test_lucas_known_values() {
+    const LUCAS_TERMS: [u32; 21] = [
+        2, 1, 3, 4, 7, 11, 18, 29, 47, 76, 123, 199, 322, 521, 843, 1364, 2207, 3571, 5778, 9349,
+        15127,
+    ];
+    for (n, &expected) in LUCAS_TERMS.iter().enumerate() {
+        assert_eq!(lucas(n as u128), BigUint::from(expected), "n={n}");
+    }
+}
+
+#[test]
+fn test_lucas_doubling_identity() {
+    // F(2n) = F(n)·L(n)
+    for n in [1u128, 7, 100, 1_000] {
+        assert_eq!(
+            fibonacci_fast_doubling_iterative(2 * n),
+            fibonacci_fast_doubling_iterative(n) * lucas(n),
+            "n={n}"
+        );
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_matches_serial() {
+    for n in [0u128, 1, 2, 10, 1_000, 94_000, 100_000] {
+        assert_eq!(
+            fibonacci_parallel(n),
+            fibonacci_fast_doubling_iterative(n),
+            "n={n}"
+        );
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_around_threshold() {
+    // F(n) compte environ 0.694·n bits : on encadre l'index à partir duquel
+    // la dernière étape de doubling franchit le seuil.
+    let threshold_index = (PARALLEL_THRESHOLD_BITS as f64 / 0.694_241_913_630_617_3) as u128;
+    for n in [
+        threshold_index - 1,
+        threshold_index,
+        threshold_index + 1,
+        2 * threshold_index - 1,
+        2 * threshold_index + 1,
+        2 * threshold_index + 3,
+    ] {
+        assert_eq!(
+            fibonacci_parallel(n),
+            fibonacci_fast_doubling_iterative(n),
+            "n={n}"
+        );
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+#[ignore = "benchmark : cargo test --release --features parallel -- --ignored --nocapture"]
+fn bench_parallel_speedup() {
+    let n = 10_000_000;
+
+    let start = std::time::Instant::now();
+    let serial = fibonacci_fast_doubling_iterative(n);
+    let serial_time = start.elapsed();
+
+    let start = std::time::Instant::now();
+    let parallel = fibonacci_parallel(n);
+    let parallel_time = start.elapsed();
+
+    assert_eq!(serial, parallel);
+    println!(
+        "F({}) : séquentiel {:?}, parallèle {:?} (x{:.2}, {} cœur(s))",
+        n,
+        serial_time,
+        parallel_time,
+        serial_time.as_secs_f64() / parallel_time.as_secs_f64(),
+        std::thread::available_parallelism().map_or(1, |c| c.get())
+    );
+}
+
+#[test]
+fn test_big_index_matches_u128_version() {
+    for n in [0u128, 1, 2, 3, 10, 127, 128, 1_000, 65_535, 65_536] {
+        assert_eq!(
+            fibonacci_fast_doubling_big(&BigUint::from(n)),
+            fibonacci_fast_doubling_iterative(n),
+            "n={n}"
+        );
+    }
+}
+
+#[test]
+fn test_with_progress_fires_once_per_bit() {
+    for n in [1u128, 2, 5, 1_000, 65_536] {
+        let mut calls = Vec::new();
+        let result = fibonacci_with_progress(n, |step, total| calls.push((step, total)));
+        assert_eq!(result, fibonacci_fast_doubling_iterative(n));
+
+        let msb_index = 127 - n.leading_zeros();
+        assert_eq!(calls.len() as u32, msb_index + 1, "n={n}");
+        let expected: Vec<(u32, u32)> = (1..=msb_index + 1).map(|k| (k, msb_index + 1)).collect();
+        assert_eq!(calls, expected, "n={n}");
+    }
+
+    let mut count = 0;
+    assert_eq!(
+        fibonacci_with_progress(0, |_, _| count += 1),
+        BigUint::from(0u32)
+    );
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_matrix_exponentiation_matches_fast_doubling() {
+    let indices = (0..=300u128).chain([1_023, 1_024, 1_025, 4_096, 10_000, 65_537]);
+    for n in indices {
+        assert_eq!(
+            fibonacci_with(n, Algorithm::MatrixExponentiation),
+            fibonacci_with(n, Algorithm::FastDoubling),
+            "n = {}",
+            n
+        );
+    }
+    assert_eq!(Algorithm::default(), Algorithm::FastDoubling);
+}
@@ -0,0 +1,31 @@
+//! Suite de tests pour l'interface C (fonctionnalité `ffi`).
+#![cfg(feature = "ffi")]
+
+use std::ffi::CStr;
+use std::ptr;
+
+use fib_rs::{fib_compute, fib_free, fibonacci_fast_doubling_iterative};
+
+/// Appelle `fib_compute`, copie la chaîne retournée puis la libère.
+fn compute(n_low: u64, n_high: u64) -> String {
+    let raw = fib_compute(n_low, n_high);
+    assert!(!raw.is_null());
+    let text = unsafe { CStr::from_ptr(raw) }.to_str().unwrap().to_owned();
+    unsafe { fib_free(raw) };
+    text
+}
+
+#[test]
+fn test_fib_compute_returns_decimal_string() {
+    assert_eq!(compute(0, 0), "0");
+    assert_eq!(compute(10, 0), "55");
+    assert_eq!(
+        compute(1_000, 0),
+        fibonacci_fast_doubling_iterative(1_000).to_string()
+    );
+}
+
+#[test]
+fn test_fib_free_accepts_null() {
+    unsafe { fib_free(ptr::null_mut()) };
+}
@@ -0,0 +1,58 @@
+//! Suite de tests pour les constantes de la formule de Binet.
+#![cfg(feature = "std")]
+
+use fib_rs::fibonacci_fast_doubling_iterative;
+use fib_rs::golden::{binet_terms, PHI, PSI, SQRT_5};
+use num_traits::ToPrimitive;
+
+#[test]
+fn test_golden_constants_relations() {
+    assert!((PHI + PSI - 1.0).abs() <= f64::EPSILON);
+    assert!((PHI - PSI - SQRT_5).abs() <= f64::EPSILON * 2.0);
+    assert!((PHI * PSI + 1.0).abs() <= f64::EPSILON);
+    assert_eq!(SQRT_5, 5f64.sqrt());
+    assert_eq!(PHI, (1.0 + 5f64.sqrt()) / 2.0);
+}
+
+#[test]
+fn test_golden_constants_are_nearest_f64() {
+    // Représentations binaires des `f64` les plus proches des valeurs
+    // exactes, calculées en précision étendue.
+    assert_eq!(PHI.to_bits(), 0x3ff9_e377_9b97_f4a8);
+    assert_eq!(PSI.to_bits(), 0xbfe3_c6ef_372f_e950);
+    assert_eq!(SQRT_5.to_bits(), 0x4001_e377_9b97_f4a8);
+    assert_eq!(PSI, 1.0 - PHI);
+}
+
+#[test]
+fn test_binet_terms_round_to_fibonacci() {
+    for n in 0..=70u32 {
+        let (phi_n, psi_n) = binet_terms(n);
+        let expected = fibonacci_fast_doubling_iterative(n as u128)
+            .to_f64()
+            .unwrap();
+        assert_eq!(((phi_n - psi_n) / SQRT_5).round(), expected, "n={n}");
+    }
+}
+
+#[test]
+fn test_binet_terms_relative_accuracy_in_f64_range() {
+    for n in (71..1475u32).step_by(7) {
+        let (phi_n, psi_n) = binet_terms(n);
+        let expected = fibonacci_fast_doubling_iterative(n as u128)
+            .to_f64()
+            .unwrap();
+        let approx = (phi_n - psi_n) / SQRT_5;
+        assert!(((approx - expected) / expected).abs() < 1e-13, "n={n}");
+    }
+    assert!(binet_terms(1474).0.is_finite());
+    assert!(binet_terms(1475).0.is_infinite());
+}
+
+#[test]
+fn test_binet_terms_psi_alternates_and_vanishes() {
+    assert_eq!(binet_terms(0), (1.0, 1.0));
+    assert!(binet_terms(5).1 < 0.0);
+    assert!(binet_terms(6).1 > 0.0);
+    assert!(binet_terms(100).1.abs() < 1e-20);
+}
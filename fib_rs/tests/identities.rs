@@ -0,0 +1,160 @@
+//! Suite de tests pour les identités et séries de Fibonacci.
+#![cfg(feature = "std")]
+
+use fib_rs::{
+    cassini_check, fibonacci_fast_doubling_iterative, fibonacci_sum, fibonacci_sum_even,
+    fibonacci_sum_odd, is_fibonacci_product_identity, lucas_prefix_sum, millin_series,
+    phi_convergents, verify_phi_power_identity,
+};
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::One;
+
+const MILLIN_LIMIT: f64 = 2.381_966_011_250_105;
+
+#[test]
+fn test_millin_series_first_terms() {
+    // 1/F(1) + 1/F(2) + 1/F(4) + 1/F(8) = 1 + 1 + 1/3 + 1/21
+    assert_eq!(millin_series(0), 0.0);
+    assert_eq!(millin_series(2), 2.0);
+    assert!((millin_series(4) - (2.0 + 1.0 / 3.0 + 1.0 / 21.0)).abs() < 1e-15);
+}
+
+#[test]
+fn test_millin_series_converges() {
+    let limit = (7.0 - 5f64.sqrt()) / 2.0;
+    assert!((limit - MILLIN_LIMIT).abs() < 1e-15);
+
+    let mut previous_error = f64::INFINITY;
+    for terms in 1..=6 {
+        let error = (limit - millin_series(terms)).abs();
+        assert!(error < previous_error, "terms={terms}");
+        previous_error = error;
+    }
+    assert!((millin_series(64) - limit).abs() < 1e-14);
+}
+
+#[test]
+fn test_phi_convergents_values() {
+    let expected = [(1u32, 1u32), (2, 1), (3, 2), (5, 3), (8, 5), (13, 8)];
+    let convergents = phi_convergents(6);
+    assert_eq!(convergents.len(), expected.len());
+    for ((p, q), (ep, eq)) in convergents.iter().zip(expected) {
+        assert_eq!((p, q), (&BigUint::from(ep), &BigUint::from(eq)));
+    }
+    assert!(phi_convergents(0).is_empty());
+}
+
+#[test]
+fn test_phi_convergents_lowest_terms_and_bracketing() {
+    for (k, (p, q)) in (1u128..).zip(phi_convergents(200)) {
+        assert_eq!(p, fibonacci_fast_doubling_iterative(k + 1));
+        assert_eq!(q, fibonacci_fast_doubling_iterative(k));
+        assert!(p.gcd(&q).is_one(), "k={k}");
+
+        // p/q < φ  <=>  p² - pq - q² < 0, puisque φ est la racine positive
+        // de x² - x - 1.
+        let (p, q) = (BigInt::from(p), BigInt::from(q));
+        let sign = &p * &p - &p * &q - &q * &q;
+        if k % 2 == 1 {
+            assert!(sign < BigInt::from(0), "k={k} devrait être sous φ");
+        } else {
+            assert!(sign > BigInt::from(0), "k={k} devrait être au-dessus de φ");
+        }
+    }
+}
+
+#[test]
+fn test_cassini_check_holds() {
+    for n in (0..=1_000u128).chain([65_536, 100_001]) {
+        assert!(cassini_check(n), "n={n}");
+    }
+}
+
+#[test]
+fn test_product_identity_accepts_consecutive_triples() {
+    for n in 1..200u128 {
+        let x = fibonacci_fast_doubling_iterative(n - 1);
+        let y = fibonacci_fast_doubling_iterative(n);
+        let z = fibonacci_fast_doubling_iterative(n + 1);
+        assert!(is_fibonacci_product_identity(&x, &y, &z), "n={n}");
+    }
+}
+
+#[test]
+fn test_product_identity_rejects_near_misses() {
+    let triple = |x: u32, y: u32, z: u32| {
+        is_fibonacci_product_identity(&BigUint::from(x), &BigUint::from(y), &BigUint::from(z))
+    };
+    assert!(triple(8, 13, 21));
+    assert!(!triple(8, 13, 22));
+    assert!(!triple(8, 14, 22));
+    assert!(!triple(4, 6, 10));
+    assert!(!triple(2, 2, 4));
+    assert!(!triple(13, 8, 21));
+
+    let x = fibonacci_fast_doubling_iterative(299);
+    let y = fibonacci_fast_doubling_iterative(300) + 1u32;
+    let z = &x + &y;
+    assert!(!is_fibonacci_product_identity(&x, &y, &z));
+}
+
+#[test]
+fn test_lucas_prefix_sum_matches_naive_summation() {
+    // L(0) = 2, L(1) = 1, L(k+2) = L(k+1) + L(k).
+    let (mut a, mut b) = (BigUint::from(2u32), BigUint::one());
+    let mut sum = BigUint::from(0u32);
+    for n in 0..=40u128 {
+        sum += &a;
+        assert_eq!(lucas_prefix_sum(n), sum, "n={n}");
+        let t = &a + &b;
+        a = b;
+        b = t;
+    }
+}
+
+#[test]
+fn test_lucas_prefix_sum_base_cases() {
+    assert_eq!(lucas_prefix_sum(0), BigUint::from(2u32));
+    assert_eq!(lucas_prefix_sum(1), BigUint::from(3u32));
+    assert_eq!(lucas_prefix_sum(2), BigUint::from(6u32));
+}
+
+#[test]
+fn test_phi_power_identity_holds() {
+    for n in 0..=100u32 {
+        assert!(verify_phi_power_identity(n), "n={n}");
+    }
+    assert!(verify_phi_power_identity(10_007));
+}
+
+/// Retourne les termes F(0)..=F(count - 1) par additions successives.
+fn naive_terms(count: usize) -> Vec<BigUint> {
+    let mut terms = vec![BigUint::from(0u32), BigUint::one()];
+    while terms.len() < count {
+        let next = &terms[terms.len() - 1] + &terms[terms.len() - 2];
+        terms.push(next);
+    }
+    terms.truncate(count);
+    terms
+}
+
+#[test]
+fn test_fibonacci_sum_matches_brute_force() {
+    let terms = naive_terms(100);
+    for n in 0..100usize {
+        let expected: BigUint = terms[..=n].iter().sum();
+        assert_eq!(fibonacci_sum(n as u128), expected, "n={n}");
+    }
+}
+
+#[test]
+fn test_fibonacci_sum_odd_and_even_match_brute_force() {
+    let terms = naive_terms(202);
+    for n in 0..100usize {
+        let odd: BigUint = (1..=n).map(|k| &terms[2 * k - 1]).sum();
+        let even: BigUint = (0..=n).map(|k| &terms[2 * k]).sum();
+        assert_eq!(fibonacci_sum_odd(n as u128), odd, "n={n}");
+        assert_eq!(fibonacci_sum_even(n as u128), even, "n={n}");
+    }
+}
@@ -0,0 +1,129 @@
+//! Suite de tests pour la recherche inverse d'index.
+#![cfg(feature = "std")]
+
+use fib_rs::{
+    fibonacci_fast_doubling_iterative, fibonacci_index, fibonacci_log10, fibonacci_set_u64,
+    index_from_magnitude, index_hint_from_prefix, is_fibonacci,
+};
+use num_bigint::BigUint;
+
+#[test]
+fn test_index_from_magnitude_round_trip() {
+    let spread = (3..2_000u128)
+        .chain((1..40).map(|k| 1u128 << k))
+        .chain([123_456_789, 10_u128.pow(12) + 7]);
+    for n in spread {
+        assert_eq!(index_from_magnitude(fibonacci_log10(n)), n, "n={n}");
+    }
+}
+
+#[test]
+fn test_index_from_magnitude_small_values() {
+    // 10^3 = 1000 est entre F(16) = 987 et F(17) = 1597.
+    assert_eq!(index_from_magnitude(3.0), 16);
+    // F(1) = F(2) = 1 : l'estimation pour 1 tombe sur l'index 2.
+    assert_eq!(index_from_magnitude(0.0), 2);
+    assert_eq!(index_from_magnitude(-1.0), 0);
+    assert_eq!(index_from_magnitude(f64::NAN), 0);
+    assert_eq!(index_from_magnitude(f64::INFINITY), u128::MAX);
+}
+
+#[test]
+fn test_fibonacci_set_u64_small_limit() {
+    let set = fibonacci_set_u64(100);
+    let mut members: Vec<u64> = set.iter().copied().collect();
+    members.sort_unstable();
+    assert_eq!(members, [0, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]);
+    for x in [4u64, 6, 7, 9, 10, 54, 56, 90, 100] {
+        assert!(!set.contains(&x), "x={x}");
+    }
+    assert_eq!(fibonacci_set_u64(0).into_iter().collect::<Vec<_>>(), [0]);
+}
+
+#[test]
+fn test_fibonacci_set_u64_matches_computed_values() {
+    let set = fibonacci_set_u64(u64::MAX);
+    // F(0)..=F(93), où F(1) = F(2) = 1 ne compte qu'une fois.
+    assert_eq!(set.len(), 93);
+    for n in 0..=93u128 {
+        let value: u64 = fibonacci_fast_doubling_iterative(n).try_into().unwrap();
+        assert!(set.contains(&value), "n={n}");
+    }
+    assert!(!set.contains(&(12_200_160_415_121_876_738 - 1)));
+}
+
+#[test]
+fn test_index_hint_from_prefix_small_values() {
+    assert_eq!(index_hint_from_prefix("0"), Some(0));
+    assert_eq!(index_hint_from_prefix("1"), Some(1));
+    assert_eq!(index_hint_from_prefix("13"), Some(7));
+    // 121393 = F(26) est le premier nombre de Fibonacci commençant par 12.
+    assert_eq!(index_hint_from_prefix("12"), Some(26));
+    assert_eq!(index_hint_from_prefix("4"), Some(19));
+}
+
+#[test]
+fn test_index_hint_from_prefix_of_real_values() {
+    for n in [20u128, 93, 94, 95, 150, 500, 1_500, 3_000] {
+        let digits = fibonacci_fast_doubling_iterative(n).to_string();
+        for len in [digits.len(), digits.len() - 1, digits.len().min(12)] {
+            let hint = index_hint_from_prefix(&digits[..len]).expect("un index doit être trouvé");
+            let hinted = fibonacci_fast_doubling_iterative(hint).to_string();
+            assert!(hinted.starts_with(&digits[..len]), "n={n}, len={len}");
+            assert!(hint <= n, "n={n}, len={len}");
+            if len >= digits.len() - 1 {
+                assert_eq!(hint, n, "n={n}, len={len}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_index_hint_from_prefix_invalid_input() {
+    assert_eq!(index_hint_from_prefix(""), None);
+    assert_eq!(index_hint_from_prefix("12a"), None);
+    assert_eq!(index_hint_from_prefix("-5"), None);
+    assert_eq!(index_hint_from_prefix("0123"), None);
+}
+
+#[test]
+fn test_is_fibonacci_small_values() {
+    let fibonacci = fibonacci_set_u64(1_000);
+    for x in 0..=1_000u64 {
+        assert_eq!(
+            is_fibonacci(&BigUint::from(x)),
+            fibonacci.contains(&x),
+            "x={x}"
+        );
+    }
+}
+
+#[test]
+fn test_is_fibonacci_large_values() {
+    for n in [100u128, 1_000, 10_007] {
+        let value = fibonacci_fast_doubling_iterative(n);
+        assert!(is_fibonacci(&value), "n={n}");
+        assert!(!is_fibonacci(&(&value + 1u32)), "n={n}");
+        assert!(!is_fibonacci(&(&value - 1u32)), "n={n}");
+    }
+}
+
+#[test]
+fn test_fibonacci_index_round_trip() {
+    assert_eq!(fibonacci_index(&BigUint::from(0u32)), Some(0));
+    // F(1) = F(2) = 1 : l'index 1 est retourné.
+    assert_eq!(fibonacci_index(&BigUint::from(1u32)), Some(1));
+    for n in (3..200u128).chain([1_000, 4_096, 50_000]) {
+        let value = fibonacci_fast_doubling_iterative(n);
+        assert_eq!(fibonacci_index(&value), Some(n), "n={n}");
+    }
+}
+
+#[test]
+fn test_fibonacci_index_rejects_non_fibonacci() {
+    for x in [4u32, 6, 7, 100, 143, 145] {
+        assert_eq!(fibonacci_index(&BigUint::from(x)), None, "x={x}");
+    }
+    let value = fibonacci_fast_doubling_iterative(5_000);
+    assert_eq!(fibonacci_index(&(value + 1u32)), None);
+}
@@ -0,0 +1,254 @@
+//! Suite de tests pour les calculs de Fibonacci en arithmétique modulaire.
+#![cfg(feature = "std")]
+
+use fib_rs::{
+    fib_mod_iter, fibonacci_coprime_indices, fibonacci_fast_doubling_iterative, fibonacci_mod,
+    fibonacci_mod_via_phi, indices_ending_in, last_digit_frequency, pisano_period,
+    pisano_structure, prime_entry_points, rank_of_apparition,
+};
+use num_bigint::BigUint;
+use num_integer::Integer;
+
+/// Calcule F(n) mod m en passant par la valeur complète (référence lente).
+fn reference_mod(n: u128, m: u64) -> u64 {
+    let r = fibonacci_fast_doubling_iterative(n) % BigUint::from(m);
+    r.try_into().unwrap()
+}
+
+#[test]
+fn test_mod_via_phi_matches_reference() {
+    // 5 est un résidu quadratique pour ces premiers (p ≡ ±1 mod 5).
+    for p in [11u64, 19, 29, 31, 41, 1_000_000_009] {
+        for n in (0..200u128).chain([1_000, 12_345]) {
+            assert_eq!(
+                fibonacci_mod_via_phi(n, p),
+                Some(reference_mod(n, p)),
+                "n={n}, p={p}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mod_via_phi_huge_index() {
+    // F(n) mod p est périodique de période divisant p - 1 lorsque 5 est un
+    // résidu quadratique.
+    let p = 29;
+    let n = u128::MAX - 7;
+    let reduced = n % (p as u128 - 1);
+    assert_eq!(fibonacci_mod_via_phi(n, p), Some(reference_mod(reduced, p)));
+}
+
+#[test]
+fn test_mod_via_phi_unsupported_moduli() {
+    // 5 n'est pas un résidu quadratique modulo 7, 13 et 17.
+    for p in [0u64, 1, 2, 5, 7, 13, 17, 21, 55] {
+        assert_eq!(fibonacci_mod_via_phi(10, p), None, "p={p}");
+    }
+}
+
+#[test]
+fn test_fib_mod_iter_matches_reference() {
+    for m in [1u64, 2, 10, 97, u64::MAX] {
+        for (n, residue) in fib_mod_iter(m).take(150).enumerate() {
+            assert_eq!(residue, reference_mod(n as u128, m), "n={n}, m={m}");
+        }
+    }
+}
+
+#[test]
+fn test_coprime_indices_match_full_gcd() {
+    for m in [1u64, 2, 6, 10, 12, 35, 89, 144] {
+        let expected: Vec<u128> = (0..=60u128)
+            .filter(|&n| {
+                fibonacci_fast_doubling_iterative(n).gcd(&BigUint::from(m)) == BigUint::from(1u32)
+            })
+            .collect();
+        assert_eq!(fibonacci_coprime_indices(m, 60), expected, "m={m}");
+    }
+}
+
+#[test]
+fn test_coprime_indices_small_cases() {
+    // F(0) = 0 n'est inversible que modulo 1.
+    assert_eq!(fibonacci_coprime_indices(1, 3), vec![0, 1, 2, 3]);
+    // F(n) est pair ssi 3 divise n.
+    assert_eq!(fibonacci_coprime_indices(2, 7), vec![1, 2, 4, 5, 7]);
+}
+
+#[test]
+fn test_pisano_structure_known_values() {
+    // (m, π(m), α(m), k)
+    let known = [
+        (1, 1, 1, 1),
+        (2, 3, 3, 1),
+        (3, 8, 4, 2),
+        (4, 6, 6, 1),
+        (5, 20, 5, 4),
+        (8, 12, 6, 2),
+        (10, 60, 15, 4),
+        (11, 10, 10, 1),
+        (13, 28, 7, 4),
+        (89, 44, 11, 4),
+    ];
+    for (m, period, rank, k) in known {
+        assert_eq!(pisano_structure(m), (period, rank, k), "m={m}");
+    }
+}
+
+#[test]
+fn test_pisano_structure_multiplier() {
+    for m in 1..500u64 {
+        let (period, rank, k) = pisano_structure(m);
+        assert_eq!(period, k * rank, "m={m}");
+        assert!([1, 2, 4].contains(&k), "m={m}, k={k}");
+        assert_eq!(rank, rank_of_apparition(m));
+        assert_eq!(reference_mod(rank as u128, m), 0, "m={m}");
+    }
+}
+
+#[test]
+fn test_indices_ending_in_matches_full_computation() {
+    for digit in 0..10u8 {
+        let expected: Vec<u128> = (0..=300u128)
+            .filter(|&n| reference_mod(n, 10) == digit as u64)
+            .collect();
+        assert_eq!(indices_ending_in(digit, 300), expected, "digit={digit}");
+    }
+}
+
+#[test]
+fn test_indices_ending_in_edge_cases() {
+    assert_eq!(indices_ending_in(0, 0), vec![0]);
+    assert_eq!(indices_ending_in(5, 4), Vec::<u128>::new());
+    assert_eq!(indices_ending_in(5, 5), vec![5]);
+    assert!(indices_ending_in(10, 1_000).is_empty());
+    // Sur un cycle complet, les chiffres 0..9 couvrent les 60 positions.
+    let total: usize = (0..10).map(|d| indices_ending_in(d, 59).len()).sum();
+    assert_eq!(total, 60);
+}
+
+#[test]
+fn test_prime_entry_points_known_values() {
+    assert_eq!(
+        prime_entry_points(11),
+        vec![(2, 3), (3, 4), (5, 5), (7, 8), (11, 10)]
+    );
+    assert!(prime_entry_points(1).is_empty());
+    assert_eq!(prime_entry_points(2), vec![(2, 3)]);
+}
+
+#[test]
+fn test_prime_entry_points_divide_fibonacci() {
+    let table = prime_entry_points(200);
+    assert_eq!(table.len(), 46);
+    for (p, rank) in table {
+        assert_eq!(reference_mod(rank as u128, p), 0, "p={p}");
+        // α(p) divise p - (5/p) : p - 1, p + 1, ou p = 5.
+        assert!(
+            p == 5 || (p - 1) % rank == 0 || (p + 1) % rank == 0,
+            "p={p}"
+        );
+    }
+}
+
+#[test]
+fn test_last_digit_frequency_base_ten() {
+    assert_eq!(last_digit_frequency(10), vec![4, 8, 4, 8, 4, 8, 4, 8, 4, 8]);
+}
+
+#[test]
+fn test_last_digit_frequency_sums_to_pisano_period() {
+    for base in 1..200u64 {
+        let frequencies = last_digit_frequency(base);
+        assert_eq!(frequencies.len(), base as usize);
+        let (period, _, _) = pisano_structure(base);
+        assert_eq!(frequencies.iter().sum::<u64>(), period, "base={base}");
+    }
+    assert_eq!(last_digit_frequency(1), vec![1]);
+    // Modulo 2 : 0, 1, 1.
+    assert_eq!(last_digit_frequency(2), vec![1, 2]);
+}
+
+#[test]
+fn test_fibonacci_mod_matches_reduced_value() {
+    let huge_modulus = fibonacci_fast_doubling_iterative(300) + 12_345u32;
+    let moduli = [
+        BigUint::from(2u32),
+        BigUint::from(10u32),
+        BigUint::from(1_000_000_007u64),
+        BigUint::from(u64::MAX) * BigUint::from(u64::MAX),
+        huge_modulus,
+    ];
+    for m in &moduli {
+        for n in (0..150u128).chain([1_000, 4_096, 65_537]) {
+            assert_eq!(
+                fibonacci_mod(n, m),
+                fibonacci_fast_doubling_iterative(n) % m,
+                "n={n}, m={m}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_fibonacci_mod_edge_moduli() {
+    let zero = BigUint::from(0u32);
+    let one = BigUint::from(1u32);
+    for n in [0u128, 1, 2, 50, u128::MAX] {
+        assert_eq!(fibonacci_mod(n, &one), zero, "n={n}");
+    }
+    assert_eq!(
+        fibonacci_mod(100, &zero),
+        fibonacci_fast_doubling_iterative(100)
+    );
+    assert_eq!(fibonacci_mod(0, &zero), zero);
+}
+
+#[test]
+fn test_fibonacci_mod_huge_index() {
+    // π(10) = 60 : F(n) mod 10 ne dépend que de n mod 60.
+    let n = u128::MAX - 3;
+    let expected = reference_mod(n % 60, 10);
+    assert_eq!(
+        fibonacci_mod(n, &BigUint::from(10u32)),
+        BigUint::from(expected)
+    );
+}
+
+#[test]
+fn test_pisano_period_known_values() {
+    let known = [
+        (1u64, 1u64),
+        (2, 3),
+        (3, 8),
+        (4, 6),
+        (5, 20),
+        (10, 60),
+        (100, 300),
+        (1_000, 1_500),
+    ];
+    for (m, period) in known {
+        assert_eq!(pisano_period(m), period, "m={m}");
+    }
+}
+
+#[test]
+fn test_pisano_period_is_a_period() {
+    for m in [7u64, 12, 97, 144, 1_000_003] {
+        let period = pisano_period(m);
+        for n in [0u128, 1, 5, 77] {
+            assert_eq!(
+                reference_mod(n, m),
+                reference_mod(n + period as u128, m),
+                "m={m}, n={n}"
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_pisano_period_zero_modulus_panics() {
+    pisano_period(0);
+}
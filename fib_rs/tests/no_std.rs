@@ -0,0 +1,43 @@
+//! Suite de tests de l'API disponible sans la fonctionnalité `std`.
+//!
+//! Ce fichier est lui-même `no_std` et n'utilise que `alloc`, comme le
+//! ferait un projet embarqué. Il est exécuté dans les deux configurations :
+//!
+//! ```text
+//! cargo test --no-default-features --test no_std
+//! ```
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+
+use fib_rs::{fibonacci_fast_doubling_iterative, fibonacci_pair, fibonacci_with, lucas, Algorithm};
+use num_bigint::BigUint;
+
+#[test]
+fn test_no_std_fast_doubling() {
+    assert_eq!(fibonacci_fast_doubling_iterative(0), BigUint::from(0u32));
+    assert_eq!(fibonacci_fast_doubling_iterative(10), BigUint::from(55u32));
+    assert_eq!(
+        fibonacci_fast_doubling_iterative(100).to_string(),
+        "354224848179261915075"
+    );
+}
+
+#[test]
+fn test_no_std_related_sequences() {
+    let (f, g) = fibonacci_pair(50);
+    assert_eq!(
+        (f, g),
+        (
+            BigUint::from(12_586_269_025u64),
+            BigUint::from(20_365_011_074u64)
+        )
+    );
+    assert_eq!(lucas(10), BigUint::from(123u32));
+    assert_eq!(
+        fibonacci_with(300, Algorithm::MatrixExponentiation),
+        fibonacci_fast_doubling_iterative(300)
+    );
+}
@@ -0,0 +1,234 @@
+//! Suite de tests pour la génération de plages de nombres de Fibonacci.
+#![cfg(feature = "std")]
+
+use std::time::Instant;
+
+use fib_rs::{
+    fib_iter, fib_iter_from, fibonacci_arithmetic_progression, fibonacci_column,
+    fibonacci_fast_doubling_iterative, shift_pair, write_range, write_range_parallel,
+};
+use num_bigint::BigUint;
+
+/// Construit la sortie attendue par additions successives depuis F(0).
+fn expected_range(start: u128, end: u128) -> String {
+    let (mut a, mut b) = (BigUint::from(0u32), BigUint::from(1u32));
+    let mut out = String::new();
+    for k in 0..=end {
+        if k >= start {
+            out.push_str(&format!("{}\n", a));
+        }
+        let t = &a + &b;
+        a = b;
+        b = t;
+    }
+    out
+}
+
+fn collect<F: FnOnce(&mut Vec<u8>) -> std::io::Result<()>>(f: F) -> String {
+    let mut buffer = Vec::new();
+    f(&mut buffer).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn test_write_range_sequential() {
+    assert_eq!(
+        collect(|w| write_range(0, 12, w)),
+        "0\n1\n1\n2\n3\n5\n8\n13\n21\n34\n55\n89\n144\n"
+    );
+    assert_eq!(
+        collect(|w| write_range(150, 420, w)),
+        expected_range(150, 420)
+    );
+}
+
+#[test]
+fn test_write_range_parallel_matches_sequential() {
+    for chunk_size in [1, 3, 7, 64, 1000] {
+        for (start, end) in [(0, 0), (0, 300), (17, 250), (500, 501)] {
+            assert_eq!(
+                collect(|w| write_range_parallel(start, end, chunk_size, w)),
+                expected_range(start, end),
+                "start={start}, end={end}, chunk_size={chunk_size}"
+            );
+        }
+    }
+}
+
+/// Writer qui compte les appels à `write`, pour vérifier que la sortie est
+/// produite au fur et à mesure et non en un seul bloc.
+#[derive(Default)]
+struct CountingWriter {
+    writes: usize,
+    bytes: Vec<u8>,
+}
+
+impl std::io::Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writes += 1;
+        self.bytes.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_range_streams_output() {
+    // Au moins une écriture par terme.
+    let mut writer = CountingWriter::default();
+    write_range(0, 99, &mut writer).unwrap();
+    assert!(writer.writes >= 100, "{} écritures", writer.writes);
+    assert_eq!(
+        String::from_utf8(writer.bytes).unwrap(),
+        expected_range(0, 99)
+    );
+
+    // Au moins une écriture par bloc de 10 termes.
+    let mut writer = CountingWriter::default();
+    write_range_parallel(0, 99, 10, &mut writer).unwrap();
+    assert!(writer.writes >= 10, "{} écritures", writer.writes);
+    assert_eq!(
+        String::from_utf8(writer.bytes).unwrap(),
+        expected_range(0, 99)
+    );
+}
+
+#[test]
+fn test_write_range_empty_when_reversed() {
+    assert_eq!(collect(|w| write_range(10, 5, w)), "");
+    assert_eq!(collect(|w| write_range_parallel(10, 5, 4, w)), "");
+}
+
+#[test]
+fn test_fibonacci_column_small_indices() {
+    let column = fibonacci_column(0, 13);
+    assert_eq!(column.len(), 13);
+    assert_eq!(
+        column,
+        [0.0, 1.0, 1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0, 34.0, 55.0, 89.0, 144.0]
+    );
+    assert_eq!(fibonacci_column(40, 2), [102_334_155.0, 165_580_141.0]);
+    assert!(fibonacci_column(5, 0).is_empty());
+}
+
+#[test]
+fn test_fibonacci_column_precision_and_overflow() {
+    let column = fibonacci_column(1470, 10);
+    assert_eq!(column.len(), 10);
+    // F(1476) est le plus grand nombre de Fibonacci représentable en f64.
+    assert!(column[6].is_finite());
+    assert!(column[7..].iter().all(|v| v.is_infinite()));
+    let expected = 1.306_989_223_763_399_3e308;
+    assert!((column[6] - expected).abs() / expected < 1e-15);
+}
+
+#[test]
+fn test_shift_pair_matches_recomputation() {
+    for n in [0u128, 1, 10, 99, 1_000] {
+        let a = fibonacci_fast_doubling_iterative(n);
+        let b = fibonacci_fast_doubling_iterative(n + 1);
+        for k in 0..20u32 {
+            let shifted = n + k as u128;
+            assert_eq!(
+                shift_pair(&a, &b, k),
+                (
+                    fibonacci_fast_doubling_iterative(shifted),
+                    fibonacci_fast_doubling_iterative(shifted + 1)
+                ),
+                "n={n}, k={k}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_shift_pair_lucas_seed() {
+    // (L(0), L(1)) = (2, 1) décalée de 5 : (L(5), L(6)) = (11, 18).
+    let (a, b) = shift_pair(&BigUint::from(2u32), &BigUint::from(1u32), 5);
+    assert_eq!((a, b), (BigUint::from(11u32), BigUint::from(18u32)));
+}
+
+#[test]
+#[ignore = "benchmark : cargo test --release -- --ignored --nocapture"]
+fn bench_write_range_parallel_speedup() {
+    let end = 20_000;
+
+    let start = Instant::now();
+    let sequential = collect(|w| write_range(0, end, w));
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel = collect(|w| write_range_parallel(0, end, 2_000, w));
+    let parallel_time = start.elapsed();
+
+    assert_eq!(sequential, parallel);
+    println!(
+        "F(0)..F({}) : séquentiel {:?}, parallèle {:?} (x{:.2})",
+        end,
+        sequential_time,
+        parallel_time,
+        sequential_time.as_secs_f64() / parallel_time.as_secs_f64()
+    );
+}
+
+#[test]
+fn test_arithmetic_progression_matches_independent_calls() {
+    for (a, d) in [
+        (0u128, 1u128),
+        (0, 7),
+        (3, 10),
+        (100, 1_000),
+        (12_345, 2_048),
+    ] {
+        let terms = fibonacci_arithmetic_progression(a, d, 12);
+        assert_eq!(terms.len(), 12);
+        for (k, term) in terms.iter().enumerate() {
+            let index = a + k as u128 * d;
+            assert_eq!(
+                *term,
+                fibonacci_fast_doubling_iterative(index),
+                "a={a}, d={d}, k={k}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_arithmetic_progression_degenerate_cases() {
+    assert!(fibonacci_arithmetic_progression(10, 3, 0).is_empty());
+    assert_eq!(
+        fibonacci_arithmetic_progression(10, 0, 3),
+        vec![BigUint::from(55u32); 3]
+    );
+}
+
+#[test]
+fn test_fib_iter_yields_sequence_from_zero() {
+    let terms: Vec<BigUint> = fib_iter().take(13).collect();
+    let expected: Vec<BigUint> = [0u32, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144]
+        .iter()
+        .map(|&v| BigUint::from(v))
+        .collect();
+    assert_eq!(terms, expected);
+}
+
+#[test]
+fn test_fib_iter_from_matches_fast_doubling() {
+    for start in [0u128, 1, 50, 1_000] {
+        for (k, term) in fib_iter_from(start).take(30).enumerate() {
+            assert_eq!(
+                term,
+                fibonacci_fast_doubling_iterative(start + k as u128),
+                "start={start}, k={k}"
+            );
+        }
+    }
+    // L'itérateur ne s'arrête jamais de lui-même.
+    assert_eq!(
+        fib_iter().nth(300),
+        Some(fibonacci_fast_doubling_iterative(300))
+    );
+}
@@ -0,0 +1,30 @@
+//! Suite de tests pour le résultat structuré `FibResult`.
+
+use fib_rs::FibResult;
+
+#[test]
+fn test_fib_result_to_json() {
+    let result = FibResult {
+        index: 10,
+        digits: 2,
+        value: "55".to_string(),
+        elapsed_micros: 3,
+    };
+    assert_eq!(
+        result.to_json(),
+        r#"{"index":10,"digits":2,"value":"55","elapsed_micros":3}"#
+    );
+}
+
+#[test]
+fn test_fib_result_to_json_keeps_large_numbers() {
+    let result = FibResult {
+        index: u128::MAX,
+        digits: 1,
+        value: "0".to_string(),
+        elapsed_micros: 0,
+    };
+    assert!(result
+        .to_json()
+        .starts_with(&format!("{{\"index\":{},", u128::MAX)));
+}
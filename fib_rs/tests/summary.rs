@@ -0,0 +1,93 @@
+//! Suite de tests pour le résumé des propriétés de F(n).
+#![cfg(feature = "std")]
+
+use fib_rs::{
+    fib_iter_from, fibonacci_fast_doubling_iterative, fibonacci_summary, multiplication_count,
+};
+use num_bigint::BigUint;
+
+#[test]
+fn test_summary_matches_direct_computation() {
+    for n in [
+        0u128, 1, 2, 10, 45, 100, 9_999, 10_000, 12_345, 50_001, 100_000,
+    ] {
+        let summary = fibonacci_summary(n);
+        let value = fibonacci_fast_doubling_iterative(n);
+        let decimal = value.to_string();
+
+        assert_eq!(summary.n, n);
+        assert_eq!(summary.digit_count, decimal.len() as u64, "n={n}");
+        assert_eq!(summary.bit_length, value.bits(), "n={n}");
+        assert_eq!(summary.is_even, !value.bit(0), "n={n}");
+        assert_eq!(summary.multiplication_count, multiplication_count(n));
+
+        let leading = summary
+            .leading_digits
+            .expect("premiers chiffres disponibles");
+        assert!(!leading.is_empty() && leading.len() <= 10, "n={n}");
+        assert!(decimal.starts_with(&leading), "n={n}");
+
+        let expected_tail = if decimal.len() >= 10 {
+            decimal[decimal.len() - 10..].to_string()
+        } else {
+            decimal.clone()
+        };
+        assert_eq!(summary.last_digits, expected_tail, "n={n}");
+    }
+}
+
+#[test]
+fn test_summary_leading_digits_dense() {
+    // Compare les premiers chiffres estimés au préfixe exact pour chaque
+    // index d'une plage, y compris ceux dont les chiffres suivants sont en
+    // …999 (n = 10 002, 13 899). Le préfixe exact à 12 chiffres est obtenu
+    // par une division par une puissance de 10 mise à jour au fil de la
+    // plage, bien moins coûteuse qu'une conversion complète.
+    let (start, end) = (10_000u128, 40_000u128);
+    let first_digits = fibonacci_fast_doubling_iterative(start).to_string().len() as u32;
+    let limit = BigUint::from(10u64.pow(12));
+    let mut power = BigUint::from(10u32).pow(first_digits - 12);
+    let mut full_length = 0;
+    for (value, n) in fib_iter_from(start).zip(start..end) {
+        let mut prefix = &value / &power;
+        while prefix >= limit {
+            power *= 10u32;
+            prefix = &value / &power;
+        }
+        let prefix = prefix.to_string();
+        let leading = fibonacci_summary(n)
+            .leading_digits
+            .expect("premiers chiffres disponibles");
+        assert!(prefix.starts_with(&leading), "n={n}: {leading} / {prefix}");
+        if leading.len() == 10 {
+            full_length += 1;
+        }
+    }
+    // Les préfixes raccourcis restent l'exception.
+    assert!(full_length > 27_000, "{full_length} préfixes complets");
+}
+
+#[test]
+fn test_summary_small_values() {
+    let summary = fibonacci_summary(12);
+    assert_eq!(summary.digit_count, 3);
+    assert_eq!(summary.bit_length, 8);
+    assert_eq!(summary.leading_digits.as_deref(), Some("144"));
+    assert_eq!(summary.last_digits, "144");
+    assert!(summary.is_even);
+
+    let zero = fibonacci_summary(0);
+    assert_eq!((zero.digit_count, zero.bit_length), (1, 0));
+    assert_eq!(zero.last_digits, "0");
+}
+
+#[test]
+fn test_summary_astronomical_index() {
+    let summary = fibonacci_summary(u128::MAX);
+    assert!(summary.leading_digits.is_none());
+    assert_eq!(summary.last_digits.len(), 10);
+    assert!(summary.is_even);
+    assert_eq!(summary.multiplication_count, 3 * 128);
+    assert_eq!(summary.digit_count, u64::MAX);
+    assert_eq!(summary.bit_length, u64::MAX);
+}